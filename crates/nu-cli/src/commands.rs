@@ -79,7 +79,7 @@ pub fn evaluate_commands(
         std::process::exit(1);
     }
 
-    match eval_block(engine_state, stack, &block, input, false, false) {
+    let exit_code = match eval_block(engine_state, stack, &block, input, false, false) {
         Ok(pipeline_data) => {
             crate::eval_file::print_table_or_error(engine_state, stack, pipeline_data, &config)
         }
@@ -89,11 +89,16 @@ pub fn evaluate_commands(
             report_error(&working_set, &err);
             std::process::exit(1);
         }
-    }
+    };
 
     if is_perf_true {
         info!("evaluate {}:{}:{}", file!(), line!(), column!());
     }
 
+    // Hand the last external's exit code back to the calling shell
+    if let Some(exit_code) = exit_code {
+        std::process::exit(exit_code as i32);
+    }
+
     Ok(())
 }
@@ -7,7 +7,7 @@ use nu_parser::parse;
 use nu_protocol::{
     ast::Call,
     engine::{EngineState, Stack, StateWorkingSet},
-    Config, PipelineData, Span, Value,
+    Config, PipelineData, Span, Type, Value,
 };
 use std::io::Write;
 
@@ -29,6 +29,48 @@ pub fn evaluate_file(
 
     let file = std::fs::read(&path).into_diagnostic()?;
 
+    // Expose the extra command line arguments to the script as $args, for
+    // scripts that don't define a main function
+    let args_id = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let args_id = working_set.add_variable(
+            b"$args".to_vec(),
+            Span::new(0, 0),
+            Type::List(Box::new(Type::String)),
+            false,
+        );
+
+        let cwd = match nu_engine::env::current_dir(engine_state, stack) {
+            Ok(cwd) => cwd,
+            Err(e) => {
+                let working_set = StateWorkingSet::new(engine_state);
+                report_error(&working_set, &e);
+                std::process::exit(1);
+            }
+        };
+
+        if let Err(err) = engine_state.merge_delta(working_set.render(), Some(stack), cwd) {
+            let working_set = StateWorkingSet::new(engine_state);
+            report_error(&working_set, &err);
+        }
+
+        args_id
+    };
+
+    stack.add_var(
+        args_id,
+        Value::List {
+            vals: args
+                .iter()
+                .map(|arg| Value::String {
+                    val: arg.clone(),
+                    span: Span::new(0, 0),
+                })
+                .collect(),
+            span: Span::new(0, 0),
+        },
+    );
+
     let mut working_set = StateWorkingSet::new(engine_state);
     trace!("parsing file: {}", path);
 
@@ -57,6 +99,13 @@ pub fn evaluate_file(
         info!("evaluate {}:{}:{}", file!(), line!(), column!());
     }
 
+    // Hand the script's exit code back to the calling shell
+    if let Some(Value::Int { val: code, .. }) = stack.get_env_var(engine_state, "LAST_EXIT_CODE") {
+        if code != 0 {
+            std::process::exit(code as i32);
+        }
+    }
+
     Ok(())
 }
 
@@ -65,7 +114,7 @@ pub fn print_table_or_error(
     stack: &mut Stack,
     mut pipeline_data: PipelineData,
     config: &Config,
-) {
+) -> Option<i64> {
     let exit_code = match &mut pipeline_data {
         PipelineData::ExternalStream { exit_code, .. } => exit_code.take(),
         _ => None,
@@ -136,6 +185,12 @@ pub fn print_table_or_error(
 
     // Make sure everything has finished
     if let Some(exit_code) = exit_code {
-        let _: Vec<_> = exit_code.into_iter().collect();
+        let mut exit_codes: Vec<_> = exit_code.into_iter().collect();
+        return match exit_codes.pop() {
+            Some(Value::Int { val, .. }) => Some(val),
+            _ => None,
+        };
     }
+
+    None
 }
@@ -10,6 +10,8 @@ mod prompt;
 mod prompt_update;
 mod reedline_config;
 mod repl;
+#[cfg(feature = "sqlite")]
+mod sqlite_history;
 mod syntax_highlight;
 mod util;
 mod validation;
@@ -24,6 +26,8 @@ pub use nu_highlight::NuHighlight;
 pub use print::Print;
 pub use prompt::NushellPrompt;
 pub use repl::evaluate_repl;
+#[cfg(feature = "sqlite")]
+pub use sqlite_history::{history_db_path, open_history_db, save_history_entry, HistoryEntry};
 pub use syntax_highlight::NuHighlighter;
 pub use util::print_pipeline_data;
 pub use util::{eval_source, gather_parent_env_vars, get_init_cwd, report_error};
@@ -124,11 +124,11 @@ impl Prompt for NushellPrompt {
             PromptEditMode::Vi(vi_mode) => match vi_mode {
                 PromptViMode::Normal => match &self.default_vi_normal_prompt_indicator {
                     Some(indicator) => indicator.as_str().into(),
-                    None => ": ".into(),
+                    None => "〉".into(),
                 },
                 PromptViMode::Insert => match &self.default_vi_insert_prompt_indicator {
                     Some(indicator) => indicator.as_str().into(),
-                    None => "〉".into(),
+                    None => ": ".into(),
                 },
             },
             PromptEditMode::Custom(str) => self.default_wrapped_custom_string(str).into(),
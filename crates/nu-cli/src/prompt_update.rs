@@ -15,6 +15,7 @@ pub(crate) const PROMPT_INDICATOR: &str = "PROMPT_INDICATOR";
 pub(crate) const PROMPT_INDICATOR_VI_INSERT: &str = "PROMPT_INDICATOR_VI_INSERT";
 pub(crate) const PROMPT_INDICATOR_VI_NORMAL: &str = "PROMPT_INDICATOR_VI_NORMAL";
 pub(crate) const PROMPT_MULTILINE_INDICATOR: &str = "PROMPT_MULTILINE_INDICATOR";
+pub(crate) const TRANSIENT_PROMPT_COMMAND: &str = "TRANSIENT_PROMPT_COMMAND";
 
 fn get_prompt_string(
     prompt: &str,
@@ -81,6 +82,23 @@ fn get_prompt_string(
         })
 }
 
+/// Get the transient prompt, if the user configured one. After a line is
+/// accepted it replaces the full prompt in the scrollback.
+pub(crate) fn get_transient_prompt_string(
+    config: &Config,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    is_perf_true: bool,
+) -> Option<String> {
+    get_prompt_string(
+        TRANSIENT_PROMPT_COMMAND,
+        config,
+        engine_state,
+        stack,
+        is_perf_true,
+    )
+}
+
 pub(crate) fn update_prompt<'prompt>(
     config: &Config,
     engine_state: &EngineState,
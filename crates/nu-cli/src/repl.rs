@@ -9,7 +9,7 @@ use log::info;
 use log::trace;
 use miette::{IntoDiagnostic, Result};
 use nu_color_config::get_color_config;
-use nu_engine::convert_env_values;
+use nu_engine::{convert_env_values, eval_block};
 use nu_parser::lex;
 use nu_protocol::engine::Stack;
 use nu_protocol::PipelineData;
@@ -18,9 +18,30 @@ use nu_protocol::{
     Config, ShellError, Span, Value, CONFIG_VARIABLE_ID,
 };
 use reedline::{DefaultHinter, Emacs, Vi};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::{sync::atomic::Ordering, time::Instant};
 
+/// Rewrite the accepted line in place, swapping the full prompt for the
+/// transient one. Only explicit newlines are counted; lines the terminal
+/// wrapped on its own stay as they are.
+fn repaint_with_transient_prompt(nu_prompt: &NushellPrompt, transient_prompt: &str, line: &str) {
+    use crossterm::{cursor, execute, terminal};
+    use reedline::Prompt;
+
+    let lines_used =
+        1 + nu_prompt.render_prompt_left().matches('\n').count() + line.matches('\n').count();
+
+    let mut stdout = std::io::stdout();
+    let _ = execute!(
+        stdout,
+        cursor::MoveUp(lines_used as u16),
+        cursor::MoveToColumn(0),
+        terminal::Clear(terminal::ClearType::FromCursorDown),
+    );
+    println!("{}{}", transient_prompt, line);
+}
+
 pub fn evaluate_repl(
     engine_state: &mut EngineState,
     stack: &mut Stack,
@@ -139,6 +160,9 @@ pub fn evaluate_repl(
         line_editor = line_editor.with_history(history);
     };
 
+    // Environment variables the env_change hooks have last seen, so we only fire on changes
+    let mut last_hook_env_vals: HashMap<String, Value> = HashMap::new();
+
     loop {
         if is_perf_true {
             info!(
@@ -239,6 +263,24 @@ pub fn evaluate_repl(
             }
         };
 
+        // Right before we start rendering the prompt, run the env_change and pre_prompt hooks
+        if let Err(err) = eval_env_change_hook(
+            config.hooks.env_change.clone(),
+            engine_state,
+            stack,
+            &mut last_hook_env_vals,
+        ) {
+            let working_set = StateWorkingSet::new(engine_state);
+            report_error(&working_set, &err);
+        }
+
+        if let Some(hook) = config.hooks.pre_prompt.clone() {
+            if let Err(err) = eval_hook(engine_state, stack, vec![], &hook) {
+                let working_set = StateWorkingSet::new(engine_state);
+                report_error(&working_set, &err);
+            }
+        }
+
         if is_perf_true {
             info!("prompt_update {}:{}:{}", file!(), line!(), column!());
         }
@@ -265,7 +307,33 @@ pub fn evaluate_repl(
         let input = line_editor.read_line(prompt);
         match input {
             Ok(Signal::Success(s)) => {
+                // Replace the prompt of the just-accepted line with the
+                // transient prompt, if one is configured, to keep the
+                // scrollback compact
+                if let Some(transient_prompt) = prompt_update::get_transient_prompt_string(
+                    &config,
+                    engine_state,
+                    stack,
+                    is_perf_true,
+                ) {
+                    repaint_with_transient_prompt(&nu_prompt, &transient_prompt, &s);
+                }
+
+                // Right before we start running the code the user gave us,
+                // fire the "pre_execution" hook
+                if let Some(hook) = config.hooks.pre_execution.clone() {
+                    if let Err(err) = eval_hook(engine_state, stack, vec![], &hook) {
+                        let working_set = StateWorkingSet::new(engine_state);
+                        report_error(&working_set, &err);
+                    }
+                }
+
                 let start_time = Instant::now();
+                #[cfg(feature = "sqlite")]
+                let start_timestamp = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_millis() as i64)
+                    .unwrap_or(0);
                 let tokens = lex(s.as_bytes(), 0, &[], &[], false);
                 // Check if this is a single call to a directory, if so auto-cd
                 let cwd = nu_engine::env::current_dir_str(engine_state, stack)?;
@@ -344,6 +412,25 @@ pub fn evaluate_repl(
                             span: Span { start: 0, end: 0 },
                         },
                     );
+
+                    #[cfg(feature = "sqlite")]
+                    if config.history_file_format == "sqlite" {
+                        let exit_status = stack
+                            .get_env_var(engine_state, "LAST_EXIT_CODE")
+                            .and_then(|v| v.as_integer().ok())
+                            .unwrap_or(0);
+
+                        crate::sqlite_history::save_history_entry(
+                            &crate::sqlite_history::HistoryEntry {
+                                command_line: &s,
+                                start_timestamp,
+                                duration_ms: start_time.elapsed().as_millis() as i64,
+                                exit_status,
+                                cwd: &cwd,
+                                session_id: engine_state.history_session_id,
+                            },
+                        );
+                    }
                 }
                 // FIXME: permanent state changes like this hopefully in time can be removed
                 // and be replaced by just passing the cwd in where needed
@@ -375,3 +462,123 @@ pub fn evaluate_repl(
 
     Ok(())
 }
+
+/// Fire the env_change hooks for environment variables that changed since the last call
+///
+/// The hook config is a record mapping environment variable names to hook values. Each hook block
+/// can take up to two positional arguments: the value before the change and the value after it.
+pub fn eval_env_change_hook(
+    env_change_hook: Option<Value>,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    last_seen: &mut HashMap<String, Value>,
+) -> Result<(), ShellError> {
+    if let Some(hook) = env_change_hook {
+        match hook {
+            Value::Record { cols, vals, .. } => {
+                for (env_name, hook_value) in cols.iter().zip(vals.iter()) {
+                    let before = last_seen.get(env_name).cloned();
+                    let after = stack.get_env_var(engine_state, env_name);
+
+                    if before != after {
+                        let span = Span::new(0, 0);
+                        let before = before.unwrap_or(Value::Nothing { span });
+                        let after = after.unwrap_or(Value::Nothing { span });
+
+                        eval_hook(
+                            engine_state,
+                            stack,
+                            vec![("$before".into(), before), ("$after".into(), after.clone())],
+                            hook_value,
+                        )?;
+
+                        last_seen.insert(env_name.to_string(), after);
+                    }
+                }
+            }
+            x => {
+                return Err(ShellError::SpannedLabeledError(
+                    "Invalid hook".into(),
+                    "$config.hooks.env_change must be a record".into(),
+                    x.span()?,
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Evaluate a hook value: either a single block or a list of blocks, run in order
+pub fn eval_hook(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    arguments: Vec<(String, Value)>,
+    value: &Value,
+) -> Result<(), ShellError> {
+    match value {
+        Value::List { vals, .. } => {
+            for val in vals {
+                eval_hook(engine_state, stack, arguments.clone(), val)?;
+            }
+        }
+        Value::Block {
+            val: block_id,
+            captures,
+            span,
+        } => {
+            let block = engine_state.get_block(*block_id);
+            let mut callee_stack = stack.captures_to_stack(captures);
+
+            for (idx, positional) in block.signature.required_positional.iter().enumerate() {
+                if let (Some(var_id), Some((_, val))) = (positional.var_id, arguments.get(idx)) {
+                    callee_stack.add_var(var_id, val.clone());
+                }
+            }
+
+            match eval_block(
+                engine_state,
+                &mut callee_stack,
+                block,
+                PipelineData::new(*span),
+                false,
+                false,
+            ) {
+                Ok(pipeline_data) => {
+                    pipeline_data.into_value(*span);
+                }
+                Err(err) => {
+                    return Err(err);
+                }
+            }
+
+            // Hooks can set environment variables (e.g. direnv-style loading), so propagate
+            // the hook's environment back to the caller
+            let caller_env_vars = stack.get_env_var_names(engine_state);
+
+            // remove env vars that are present in the caller but not in the hook
+            // (the hook hid them)
+            for var in caller_env_vars.iter() {
+                if !callee_stack.has_env_var(engine_state, var) {
+                    stack.remove_env_var(engine_state, var);
+                }
+            }
+
+            // add new env vars from the hook to the caller
+            for env_vars in callee_stack.env_vars {
+                for (var, value) in env_vars {
+                    stack.add_env_var(var, value);
+                }
+            }
+        }
+        x => {
+            return Err(ShellError::SpannedLabeledError(
+                "Invalid hook".into(),
+                "hooks can only be blocks or lists of blocks".into(),
+                x.span()?,
+            ));
+        }
+    }
+
+    Ok(())
+}
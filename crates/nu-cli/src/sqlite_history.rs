@@ -0,0 +1,66 @@
+use rusqlite::{params, Connection};
+use std::path::PathBuf;
+
+/// Where the structured (sqlite) history lives, next to the plain-text one
+pub fn history_db_path() -> Option<PathBuf> {
+    nu_path::config_dir().map(|mut path| {
+        path.push("nushell");
+        path.push("history.sqlite3");
+        path
+    })
+}
+
+/// Open (creating it if needed) the structured history database
+pub fn open_history_db() -> Option<Connection> {
+    let path = history_db_path()?;
+
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    let connection = Connection::open(path).ok()?;
+    connection
+        .execute(
+            "CREATE TABLE IF NOT EXISTS history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                command_line TEXT,
+                start_timestamp INTEGER,
+                duration_ms INTEGER,
+                exit_status INTEGER,
+                cwd TEXT,
+                session_id INTEGER
+            )",
+            [],
+        )
+        .ok()?;
+
+    Some(connection)
+}
+
+pub struct HistoryEntry<'a> {
+    pub command_line: &'a str,
+    pub start_timestamp: i64,
+    pub duration_ms: i64,
+    pub exit_status: i64,
+    pub cwd: &'a str,
+    pub session_id: i64,
+}
+
+/// Record one accepted REPL line. History is best-effort: failures are
+/// silently ignored so a broken database never breaks the shell.
+pub fn save_history_entry(entry: &HistoryEntry) {
+    if let Some(connection) = open_history_db() {
+        let _ = connection.execute(
+            "INSERT INTO history (command_line, start_timestamp, duration_ms, exit_status, cwd, session_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                entry.command_line,
+                entry.start_timestamp,
+                entry.duration_ms,
+                entry.exit_status,
+                entry.cwd,
+                entry.session_id,
+            ],
+        );
+    }
+}
@@ -6,6 +6,40 @@ use nu_protocol::engine::{EngineState, StateWorkingSet};
 use nu_protocol::Config;
 use reedline::{Highlighter, StyledText};
 
+/// Check if a command name can be found as an executable somewhere on PATH
+fn is_known_external(name: &str, engine_state: &EngineState) -> bool {
+    let paths = match engine_state.env_vars.get("PATH") {
+        Some(paths) => paths,
+        #[cfg(windows)]
+        None => match engine_state.env_vars.get("Path") {
+            Some(paths) => paths,
+            None => return false,
+        },
+        #[cfg(not(windows))]
+        None => return false,
+    };
+
+    if let Ok(paths) = paths.as_list() {
+        for path in paths {
+            let path = path.as_string().unwrap_or_default();
+            let item = std::path::Path::new(&path).join(name);
+
+            if is_executable::is_executable(&item) {
+                return true;
+            }
+
+            #[cfg(windows)]
+            for ext in ["exe", "bat", "cmd", "com", "ps1"] {
+                if is_executable::is_executable(item.with_extension(ext)) {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
 pub struct NuHighlighter {
     pub engine_state: EngineState,
     pub config: Config,
@@ -95,10 +129,14 @@ impl Highlighter for NuHighlighter {
                 )),
                 FlatShape::External => {
                     // nushell ExternalCommand
-                    output.push((
-                        get_shape_color(shape.1.to_string(), &self.config),
-                        next_token,
-                    ))
+                    // Highlight externals that resolve to an executable on PATH differently
+                    // from unknown commands
+                    let shape_name = if is_known_external(&next_token, &self.engine_state) {
+                        "shape_external_resolved".to_string()
+                    } else {
+                        shape.1.to_string()
+                    };
+                    output.push((get_shape_color(shape_name, &self.config), next_token))
                 }
                 FlatShape::ExternalArg => {
                     // nushell ExternalWord
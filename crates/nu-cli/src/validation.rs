@@ -11,7 +11,13 @@ impl Validator for NuValidator {
         let mut working_set = StateWorkingSet::new(&self.engine_state);
         let (_, err) = parse(&mut working_set, None, line.as_bytes(), false, &[]);
 
-        if matches!(err, Some(ParseError::UnexpectedEof(..))) {
+        // Unclosed delimiters also mean more input is coming. This keeps a
+        // pasted multi-line block pending as one buffer instead of running it
+        // line by line as each newline arrives.
+        if matches!(
+            err,
+            Some(ParseError::UnexpectedEof(..)) | Some(ParseError::Unclosed(..))
+        ) {
             ValidationResult::Incomplete
         } else {
             ValidationResult::Complete
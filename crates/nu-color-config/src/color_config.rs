@@ -1,6 +1,6 @@
-use crate::nu_style::{color_from_hex, color_string_to_nustyle};
+use crate::nu_style::{color_from_hex, color_string_to_nustyle, parse_nustyle, NuStyle};
 use nu_ansi_term::{Color, Style};
-use nu_protocol::Config;
+use nu_protocol::{Config, Value};
 use nu_table::{Alignment, TextStyle};
 use std::collections::HashMap;
 
@@ -166,9 +166,41 @@ pub fn lookup_ansi_color_style(s: &str) -> Style {
     }
 }
 
-fn update_hashmap(key: &str, val: &str, hm: &mut HashMap<String, Style>) {
-    // eprintln!("key: {}, val: {}", &key, &val);
-    let color = lookup_ansi_color_style(val);
+fn update_hashmap(key: &str, value: &Value, hm: &mut HashMap<String, Style>) {
+    let color = match value {
+        // Most records are stringified while the config is loaded, but a
+        // record assigned later (e.g. `let-env` tinkering) still lands here.
+        Value::Record { cols, vals, .. } => {
+            let mut nu_style = NuStyle {
+                fg: None,
+                bg: None,
+                attr: None,
+            };
+
+            for (col, val) in cols.iter().zip(vals) {
+                if let Ok(val) = val.as_string() {
+                    match col.as_str() {
+                        "fg" => nu_style.fg = Some(val),
+                        "bg" => nu_style.bg = Some(val),
+                        "attr" => nu_style.attr = Some(val),
+                        _ => (),
+                    }
+                }
+            }
+
+            parse_nustyle(nu_style)
+        }
+        value => match value.as_string() {
+            Ok(value) => lookup_ansi_color_style(&value),
+            Err(_) => {
+                // Leave the built-in default in place rather than panicking
+                // over one bad entry in the theme.
+                eprintln!("$config.color_config.{} is not a string or record", key);
+                return;
+            }
+        },
+    };
+
     if let Some(v) = hm.get_mut(key) {
         *v = color;
     } else {
@@ -210,15 +242,7 @@ pub fn get_color_config(config: &Config) -> HashMap<String, Style> {
     hm.insert("hints".to_string(), Color::DarkGray.normal());
 
     for (key, value) in &config.color_config {
-        let value = value
-            .as_string()
-            .expect("the only values for config color must be strings");
-        update_hashmap(key, &value, &mut hm);
-
-        // eprintln!(
-        //     "config: {}:{}\t\t\thashmap: {}:{:?}",
-        //     &key, &value, &key, &hm[key]
-        // );
+        update_hashmap(key, value, &mut hm);
     }
 
     hm
@@ -405,7 +429,7 @@ fn test_hm() {
         Style::default().on(Color::Rgb(128, 128, 128)),
     );
 
-    update_hashmap("primitive_int", "green", &mut hm);
+    update_hashmap("primitive_int", &Value::test_string("green"), &mut hm);
 
     assert_eq!(hm["primitive_int"], Color::Green.normal());
 }
@@ -1,7 +1,9 @@
 mod color_config;
+mod lscolor;
 mod nu_style;
 mod shape_color;
 
 pub use color_config::*;
+pub use lscolor::*;
 pub use nu_style::*;
 pub use shape_color::*;
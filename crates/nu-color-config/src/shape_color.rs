@@ -17,8 +17,10 @@ pub fn get_shape_color(shape: String, conf: &Config) -> Style {
             "shape_range" => Style::new().fg(Color::Yellow).bold(),
             "shape_internalcall" => Style::new().fg(Color::Cyan).bold(),
             "shape_external" => Style::new().fg(Color::Cyan),
+            "shape_external_resolved" => Style::new().fg(Color::LightCyan).bold(),
             "shape_externalarg" => Style::new().fg(Color::Green).bold(),
             "shape_literal" => Style::new().fg(Color::Blue),
+            "shape_match_pattern" => Style::new().fg(Color::Green),
             "shape_operator" => Style::new().fg(Color::Yellow),
             "shape_signature" => Style::new().fg(Color::Green).bold(),
             "shape_string" => Style::new().fg(Color::Green),
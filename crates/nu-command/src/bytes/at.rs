@@ -0,0 +1,181 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::ast::{CellPath, RangeInclusion};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::Category;
+use nu_protocol::{Example, PipelineData, Range, ShellError, Signature, Span, SyntaxShape, Value};
+
+struct Arguments {
+    start: isize,
+    end: isize,
+    column_paths: Vec<CellPath>,
+}
+
+#[derive(Clone)]
+pub struct BytesAt;
+
+impl Command for BytesAt {
+    fn name(&self) -> &str {
+        "bytes at"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bytes at")
+            .required("range", SyntaxShape::Range, "the range to get bytes")
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "optionally get bytes by column paths",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Get bytes defined by a range. Note that the start is included but the end is excluded, and that the first byte is index 0."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["slice"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let range: Range = call.req(engine_state, stack, 0)?;
+        let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+
+        let (start, end) = match (&range.from, &range.to) {
+            (Value::Int { val: start, .. }, Value::Int { val: end, .. }) => {
+                let end = if range.inclusion == RangeInclusion::Inclusive {
+                    end.saturating_add(1)
+                } else {
+                    *end
+                };
+                (*start as isize, end as isize)
+            }
+            _ => {
+                return Err(ShellError::UnsupportedInput(
+                    "Only integer ranges are supported".into(),
+                    call.head,
+                ))
+            }
+        };
+
+        let arg = Arguments {
+            start,
+            end,
+            column_paths,
+        };
+        operate(engine_state, call, input, arg)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get a subbytes `0x[10 01]` from the binary `0x[33 44 55 10 01 13]`",
+                example: " 0x[33 44 55 10 01 13] | bytes at 3..<5",
+                result: Some(Value::Binary {
+                    val: vec![0x10, 0x01],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Get the remaining characters from a starting index",
+                example: " 0x[33 44 55 10 01 13] | bytes at 3..",
+                result: Some(Value::Binary {
+                    val: vec![0x10, 0x01, 0x13],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Get the characters from the beginning until ending index",
+                example: " 0x[33 44 55 10 01 13] | bytes at ..<4",
+                result: Some(Value::Binary {
+                    val: vec![0x33, 0x44, 0x55, 0x10],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn operate(
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+    arg: Arguments,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let (start, end) = (arg.start, arg.end);
+    let column_paths = arg.column_paths;
+    input.map(
+        move |v| {
+            if column_paths.is_empty() {
+                action(&v, start, end, head)
+            } else {
+                let mut ret = v;
+                for path in &column_paths {
+                    let r = ret.update_cell_path(
+                        &path.members,
+                        Box::new(move |old| action(old, start, end, head)),
+                    );
+                    if let Err(error) = r {
+                        return Value::Error { error };
+                    }
+                }
+                ret
+            }
+        },
+        engine_state.ctrlc.clone(),
+    )
+}
+
+fn action(input: &Value, start: isize, end: isize, head: Span) -> Value {
+    match input {
+        Value::Binary { val, .. } => {
+            let len = val.len() as isize;
+            // Negative indexes count from the end, like `str substring`.
+            let start = if start < 0 { start + len } else { start };
+            let end = if end < 0 { end + len } else { end };
+
+            if start > end {
+                Value::Binary {
+                    val: vec![],
+                    span: head,
+                }
+            } else {
+                let start = start.clamp(0, len) as usize;
+                let end = end.clamp(0, len) as usize;
+                Value::Binary {
+                    val: val[start..end].to_vec(),
+                    span: head,
+                }
+            }
+        }
+        other => Value::Error {
+            error: ShellError::UnsupportedInput(
+                format!(
+                    "Input's type is {}. This command only works with binary.",
+                    other.get_type()
+                ),
+                head,
+            ),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(BytesAt {})
+    }
+}
@@ -0,0 +1,15 @@
+mod add;
+mod at;
+mod bytes_;
+mod collect;
+mod length;
+mod replace;
+mod starts_with;
+
+pub use add::BytesAdd;
+pub use at::BytesAt;
+pub use bytes_::Bytes;
+pub use collect::BytesCollect;
+pub use length::BytesLen;
+pub use replace::BytesReplace;
+pub use starts_with::BytesStartsWith;
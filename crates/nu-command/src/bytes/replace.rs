@@ -0,0 +1,167 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::ast::CellPath;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::Category;
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
+
+struct Arguments {
+    find: Vec<u8>,
+    replace: Vec<u8>,
+    all: bool,
+    column_paths: Vec<CellPath>,
+}
+
+#[derive(Clone)]
+pub struct BytesReplace;
+
+impl Command for BytesReplace {
+    fn name(&self) -> &str {
+        "bytes replace"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bytes replace")
+            .required("find", SyntaxShape::Binary, "the pattern to find")
+            .required("replace", SyntaxShape::Binary, "the replacement pattern")
+            .switch("all", "replace all occurrences of find binary", Some('a'))
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "optionally find and replace binary by column paths",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Find and replace binary"
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["search", "shift", "switch"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let find: Vec<u8> = call.req(engine_state, stack, 0)?;
+        if find.is_empty() {
+            return Err(ShellError::UnsupportedInput(
+                "the pattern to find cannot be empty".to_string(),
+                call.head,
+            ));
+        }
+
+        let arg = Arguments {
+            find,
+            replace: call.req(engine_state, stack, 1)?,
+            all: call.has_flag("all"),
+            column_paths: call.rest(engine_state, stack, 2)?,
+        };
+
+        operate(engine_state, call, input, arg)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find and replace contents",
+                example: "0x[10 AA FF AA FF] | bytes replace 0x[10 AA] 0x[FF]",
+                result: Some(Value::Binary {
+                    val: vec![0xFF, 0xFF, 0xAA, 0xFF],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Find and replace all occurrences of find binary",
+                example: "0x[10 AA 10 BB 10] | bytes replace -a 0x[10] 0x[A0]",
+                result: Some(Value::Binary {
+                    val: vec![0xA0, 0xAA, 0xA0, 0xBB, 0xA0],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn operate(
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+    arg: Arguments,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    input.map(
+        move |v| {
+            if arg.column_paths.is_empty() {
+                action(&v, &arg.find, &arg.replace, arg.all, head)
+            } else {
+                let mut ret = v;
+                for path in &arg.column_paths {
+                    let find = arg.find.clone();
+                    let replace = arg.replace.clone();
+                    let all = arg.all;
+                    let r = ret.update_cell_path(
+                        &path.members,
+                        Box::new(move |old| action(old, &find, &replace, all, head)),
+                    );
+                    if let Err(error) = r {
+                        return Value::Error { error };
+                    }
+                }
+                ret
+            }
+        },
+        engine_state.ctrlc.clone(),
+    )
+}
+
+fn action(input: &Value, find: &[u8], replace: &[u8], all: bool, head: Span) -> Value {
+    match input {
+        Value::Binary { val, .. } => {
+            let mut result = vec![];
+            let mut left = 0;
+            let mut replaced = false;
+            while left + find.len() <= val.len() {
+                if &val[left..left + find.len()] == find && (all || !replaced) {
+                    result.extend_from_slice(replace);
+                    left += find.len();
+                    replaced = true;
+                } else {
+                    result.push(val[left]);
+                    left += 1;
+                }
+            }
+            result.extend_from_slice(&val[left..]);
+            Value::Binary {
+                val: result,
+                span: head,
+            }
+        }
+        other => Value::Error {
+            error: ShellError::UnsupportedInput(
+                format!(
+                    "Input's type is {}. This command only works with binary.",
+                    other.get_type()
+                ),
+                head,
+            ),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(BytesReplace {})
+    }
+}
@@ -0,0 +1,255 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+use indexmap::IndexMap;
+
+#[derive(Clone)]
+pub struct Histogram;
+
+impl Command for Histogram {
+    fn name(&self) -> &str {
+        "histogram"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("histogram")
+            .optional(
+                "column-name",
+                SyntaxShape::String,
+                "column name to calculate frequency, do not need to provide if input is a list",
+            )
+            .optional(
+                "frequency-column-name",
+                SyntaxShape::String,
+                "histogram's frequency column, default to be frequency column output",
+            )
+            .named(
+                "percentage-type",
+                SyntaxShape::String,
+                "percentage calculate method, can be 'relative' (against the most frequent value) or 'normalize' (against the total count), defaults to 'relative'",
+                Some('t'),
+            )
+            .named(
+                "bar-width",
+                SyntaxShape::Int,
+                "width of the frequency bar for the most frequent value (defaults to 100)",
+                Some('w'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Creates a new table with a histogram based on the column name passed in."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get a histogram for the types of files",
+                example: "ls | histogram type",
+                result: None,
+            },
+            Example {
+                description:
+                    "Get a histogram for the types of files, with frequency column named freq",
+                example: "ls | histogram type freq",
+                result: None,
+            },
+            Example {
+                description: "Get a histogram for a list of numbers",
+                example: "echo [1 2 1] | histogram",
+                result: None,
+            },
+            Example {
+                description: "Get a histogram for a list of numbers, and percentage is based on the total amount of numbers",
+                example: "echo [1 2 3 1 1 1 2 2 1 1] | histogram --percentage-type normalize",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        // input check.
+        let column_name: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let frequency_name_arg: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
+        let frequency_column_name = match frequency_name_arg {
+            Some(inner) => {
+                let forbidden_column_names = ["value", "count", "percentage"];
+                if forbidden_column_names.contains(&inner.item.as_str()) {
+                    return Err(ShellError::SpannedLabeledError(
+                        "Invalid column name".to_string(),
+                        format!(
+                            "{} is a reserved column name, please use another one",
+                            inner.item
+                        ),
+                        inner.span,
+                    ));
+                }
+                inner.item
+            }
+            None => "frequency".to_string(),
+        };
+
+        let percentage_type: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "percentage-type")?;
+        let calc_method = match percentage_type {
+            None => PercentageCalcMethod::Relative,
+            Some(inner) => match inner.item.as_str() {
+                "normalize" => PercentageCalcMethod::Normalize,
+                "relative" => PercentageCalcMethod::Relative,
+                _ => {
+                    return Err(ShellError::SpannedLabeledError(
+                        "Invalid percentage-type".to_string(),
+                        "percentage-type can only be 'normalize' or 'relative'".to_string(),
+                        inner.span,
+                    ))
+                }
+            },
+        };
+
+        let bar_width: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "bar-width")?;
+        let bar_width = match bar_width {
+            Some(inner) => {
+                if inner.item < 1 {
+                    return Err(ShellError::SpannedLabeledError(
+                        "Invalid bar-width".to_string(),
+                        "bar-width must be a positive integer".to_string(),
+                        inner.span,
+                    ));
+                }
+                inner.item as usize
+            }
+            None => 100,
+        };
+
+        let span = call.head;
+        let config = stack.get_config().unwrap_or_default();
+        let data_as_value = input.into_value(span);
+
+        // `input` is not a list, here we can return an error.
+        match data_as_value.as_list() {
+            Ok(list_value) => run_histogram(
+                list_value.to_vec(),
+                column_name,
+                frequency_column_name,
+                calc_method,
+                bar_width,
+                span,
+                &config,
+            ),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum PercentageCalcMethod {
+    Normalize,
+    Relative,
+}
+
+fn run_histogram(
+    values: Vec<Value>,
+    column_name: Option<Spanned<String>>,
+    freq_column: String,
+    calc_method: PercentageCalcMethod,
+    bar_width: usize,
+    head_span: Span,
+    config: &Config,
+) -> Result<PipelineData, ShellError> {
+    let mut inputs = vec![];
+    match column_name {
+        None => {
+            // some invalid input scenario needs to handle:
+            // Expect input is a list of hashable value, if one value is not hashable, throw out error.
+            for v in values {
+                inputs.push(v.into_string("", config));
+            }
+        }
+        Some(ref col) => {
+            // some invalid input scenario needs to handle:
+            // * item in `input` is not a record, just skip it.
+            // * a record doesn't contain specific column, just skip it.
+            let col_name = &col.item;
+            for v in values {
+                if let Value::Record { cols, vals, .. } = v {
+                    if let Some(index) = cols.iter().position(|c| c == col_name) {
+                        if let Some(val) = vals.get(index) {
+                            inputs.push(val.into_string("", config));
+                        }
+                    }
+                }
+            }
+
+            if inputs.is_empty() {
+                return Err(ShellError::CantFindColumn(col.span, head_span));
+            }
+        }
+    }
+
+    let value_column_name = column_name
+        .map(|x| x.item)
+        .unwrap_or_else(|| "value".to_string());
+
+    let mut counter: IndexMap<String, i64> = IndexMap::new();
+    for input in inputs {
+        *counter.entry(input).or_insert(0) += 1;
+    }
+
+    let total_cnt: i64 = counter.values().sum();
+    let max_cnt = counter.values().max().copied().unwrap_or(1);
+
+    let mut result = vec![];
+    for (val, count) in counter.into_iter() {
+        let percentage = match calc_method {
+            PercentageCalcMethod::Normalize => count as f64 / total_cnt as f64 * 100.0,
+            PercentageCalcMethod::Relative => count as f64 / max_cnt as f64 * 100.0,
+        };
+        let freq = "*".repeat((percentage / 100.0 * bar_width as f64).floor() as usize);
+
+        result.push(Value::Record {
+            cols: vec![
+                value_column_name.clone(),
+                "count".to_string(),
+                "percentage".to_string(),
+                freq_column.clone(),
+            ],
+            vals: vec![
+                Value::String {
+                    val,
+                    span: head_span,
+                },
+                Value::Int {
+                    val: count,
+                    span: head_span,
+                },
+                Value::String {
+                    val: format!("{:.2}%", percentage),
+                    span: head_span,
+                },
+                Value::String {
+                    val: freq,
+                    span: head_span,
+                },
+            ],
+            span: head_span,
+        });
+    }
+
+    Ok(Value::List {
+        vals: result,
+        span: head_span,
+    }
+    .into_pipeline_data())
+}
@@ -0,0 +1,3 @@
+mod histogram;
+
+pub use histogram::Histogram;
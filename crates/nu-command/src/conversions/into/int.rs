@@ -81,6 +81,11 @@ impl Command for SubCommand {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Convert bytes to integer",
+                example: "0x[02] | into int",
+                result: Some(Value::test_int(2)),
+            },
             Example {
                 description: "Convert to integer from binary",
                 example: "'1101' | into int -r 2",
@@ -177,12 +182,37 @@ pub fn action(input: &Value, span: Span, radix: u32) -> Value {
                 Value::Int { val: 0, span }
             }
         }
+        Value::Binary { val, .. } => int_from_endian(val, span),
         _ => Value::Error {
             error: ShellError::UnsupportedInput("'into int' for unsupported type".into(), span),
         },
     }
 }
 
+// Interpret raw bytes as a machine-endian integer, to mirror what 'into binary' produces
+fn int_from_endian(bytes: &[u8], span: Span) -> Value {
+    if bytes.len() > 8 {
+        return Value::Error {
+            error: ShellError::UnsupportedInput(
+                "binary input is too large to convert to int (8 bytes max)".into(),
+                span,
+            ),
+        };
+    }
+
+    let mut buf = [0u8; 8];
+    if cfg!(target_endian = "little") {
+        buf[..bytes.len()].copy_from_slice(bytes);
+    } else {
+        buf[8 - bytes.len()..].copy_from_slice(bytes);
+    }
+
+    Value::Int {
+        val: i64::from_ne_bytes(buf),
+        span,
+    }
+}
+
 fn convert_int(input: &Value, head: Span, radix: u32) -> Value {
     let i = match input {
         Value::Int { val, .. } => val.to_string(),
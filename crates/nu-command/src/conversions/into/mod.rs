@@ -6,6 +6,7 @@ mod decimal;
 mod duration;
 mod filesize;
 mod int;
+mod record;
 mod string;
 
 pub use self::bool::SubCommand as IntoBool;
@@ -16,4 +17,5 @@ pub use datetime::SubCommand as IntoDatetime;
 pub use decimal::SubCommand as IntoDecimal;
 pub use duration::SubCommand as IntoDuration;
 pub use int::SubCommand as IntoInt;
+pub use record::SubCommand as IntoRecord;
 pub use string::SubCommand as IntoString;
@@ -0,0 +1,296 @@
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "into record"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into record").category(Category::Conversions)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert value to record"
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["convert"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let config = stack.get_config().unwrap_or_default();
+        into_record(engine_state, call, input, &config)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let span = Span::test_data();
+        vec![
+            Example {
+                description: "Convert from list of key/value pairs to record",
+                example: "[[foo bar] [baz quux]] | into record",
+                result: Some(Value::Record {
+                    cols: vec!["foo".to_string(), "baz".to_string()],
+                    vals: vec![Value::test_string("bar"), Value::test_string("quux")],
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert from a two-column table to record",
+                example: "[[key value]; [foo 3] [bar 5]] | into record",
+                result: Some(Value::Record {
+                    cols: vec!["foo".to_string(), "bar".to_string()],
+                    vals: vec![Value::test_int(3), Value::test_int(5)],
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert from list to record",
+                example: "[1 2 3] | into record",
+                result: Some(Value::Record {
+                    cols: vec!["0".to_string(), "1".to_string(), "2".to_string()],
+                    vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert from range to record",
+                example: "0..2 | into record",
+                result: Some(Value::Record {
+                    cols: vec!["0".to_string(), "1".to_string(), "2".to_string()],
+                    vals: vec![Value::test_int(0), Value::test_int(1), Value::test_int(2)],
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert duration to record",
+                example: "72hr | into record",
+                result: Some(Value::Record {
+                    cols: vec!["day".to_string()],
+                    vals: vec![Value::test_int(3)],
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert date to record",
+                example: "2020-04-12T22:10:57+02:00 | into record",
+                result: Some(Value::Record {
+                    cols: vec![
+                        "year".into(),
+                        "month".into(),
+                        "day".into(),
+                        "hour".into(),
+                        "minute".into(),
+                        "second".into(),
+                        "timezone".into(),
+                    ],
+                    vals: vec![
+                        Value::test_int(2020),
+                        Value::test_int(4),
+                        Value::test_int(12),
+                        Value::test_int(22),
+                        Value::test_int(10),
+                        Value::test_int(57),
+                        Value::test_string("+02:00"),
+                    ],
+                    span,
+                }),
+            },
+        ]
+    }
+}
+
+fn into_record(
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+    config: &Config,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let input = input.into_value(span);
+    let res = match input {
+        Value::Date { val, span } => parse_date_into_record(val, span),
+        Value::Duration { val, span } => parse_duration_into_record(val, span),
+        Value::List { vals, span } => parse_list_into_record(vals, span, config),
+        Value::Range { val, span } => {
+            let mut cols = vec![];
+            let mut values = vec![];
+            for (idx, val) in val.into_range_iter(engine_state.ctrlc.clone())?.enumerate() {
+                cols.push(idx.to_string());
+                values.push(val);
+            }
+            Value::Record {
+                cols,
+                vals: values,
+                span,
+            }
+        }
+        Value::Record { cols, vals, span } => Value::Record { cols, vals, span },
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                format!(
+                    "Input's type is {}. This command only works with list, range, date, duration, and record input.",
+                    other.get_type()
+                ),
+                span,
+            ))
+        }
+    };
+    Ok(res.into_pipeline_data())
+}
+
+fn parse_list_into_record(vals: Vec<Value>, span: Span, config: &Config) -> Value {
+    let mut cols = vec![];
+    let mut values = vec![];
+
+    let all_pairs = !vals.is_empty()
+        && vals
+            .iter()
+            .all(|item| matches!(item, Value::List { vals, .. } if vals.len() == 2));
+    let all_two_column_rows = !vals.is_empty()
+        && vals
+            .iter()
+            .all(|item| matches!(item, Value::Record { cols, .. } if cols.len() == 2));
+
+    if all_pairs {
+        // A list of [key value] pairs
+        for item in vals {
+            if let Value::List { vals: pair, .. } = item {
+                let mut pair = pair.into_iter();
+                if let (Some(key), Some(value)) = (pair.next(), pair.next()) {
+                    cols.push(key.into_string("", config));
+                    values.push(value);
+                }
+            }
+        }
+    } else if all_two_column_rows {
+        // A two-column table: the first column holds the keys, the second the values
+        for item in vals {
+            if let Value::Record { vals: mut row, .. } = item {
+                let value = row.pop();
+                let key = row.pop();
+                if let (Some(key), Some(value)) = (key, value) {
+                    cols.push(key.into_string("", config));
+                    values.push(value);
+                }
+            }
+        }
+    } else {
+        for (idx, val) in vals.into_iter().enumerate() {
+            cols.push(idx.to_string());
+            values.push(val);
+        }
+    }
+
+    Value::Record {
+        cols,
+        vals: values,
+        span,
+    }
+}
+
+fn parse_date_into_record(date: DateTime<FixedOffset>, span: Span) -> Value {
+    let cols = vec![
+        "year".into(),
+        "month".into(),
+        "day".into(),
+        "hour".into(),
+        "minute".into(),
+        "second".into(),
+        "timezone".into(),
+    ];
+    let vals = vec![
+        Value::Int {
+            val: date.year() as i64,
+            span,
+        },
+        Value::Int {
+            val: date.month() as i64,
+            span,
+        },
+        Value::Int {
+            val: date.day() as i64,
+            span,
+        },
+        Value::Int {
+            val: date.hour() as i64,
+            span,
+        },
+        Value::Int {
+            val: date.minute() as i64,
+            span,
+        },
+        Value::Int {
+            val: date.second() as i64,
+            span,
+        },
+        Value::String {
+            val: date.offset().to_string(),
+            span,
+        },
+    ];
+    Value::Record { cols, vals, span }
+}
+
+fn parse_duration_into_record(duration: i64, span: Span) -> Value {
+    let units = [
+        ("week", 1000 * 1000 * 1000 * 60 * 60 * 24 * 7),
+        ("day", 1000 * 1000 * 1000 * 60 * 60 * 24),
+        ("hour", 1000 * 1000 * 1000 * 60 * 60),
+        ("minute", 1000 * 1000 * 1000 * 60),
+        ("second", 1000 * 1000 * 1000),
+        ("millisecond", 1000 * 1000),
+        ("microsecond", 1000),
+        ("nanosecond", 1),
+    ];
+
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    let sign = if duration < 0 { -1 } else { 1 };
+    let mut remainder = duration.abs();
+
+    for (unit, nanos) in units {
+        let amount = remainder / nanos;
+        remainder %= nanos;
+        if amount != 0 {
+            cols.push(unit.to_string());
+            vals.push(Value::Int {
+                val: sign * amount,
+                span,
+            });
+        }
+    }
+
+    if cols.is_empty() {
+        cols.push("nanosecond".to_string());
+        vals.push(Value::Int { val: 0, span });
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
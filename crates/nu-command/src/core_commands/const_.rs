@@ -0,0 +1,100 @@
+use nu_engine::eval_expression_with_input;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, Signature, SyntaxShape, Value};
+
+#[derive(Clone)]
+pub struct Const;
+
+impl Command for Const {
+    fn name(&self) -> &str {
+        "const"
+    }
+
+    fn usage(&self) -> &str {
+        "Create a parse-time constant."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("const")
+            .required("const_name", SyntaxShape::VarWithOptType, "constant name")
+            .required(
+                "initial_value",
+                SyntaxShape::Keyword(b"=".to_vec(), Box::new(SyntaxShape::Expression)),
+                "equals sign followed by constant value",
+            )
+            .category(Category::Core)
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The value of a constant is evaluated during parsing, which means it can be
+used in places where only parse-time values are accepted, such as the file name
+passed to `source`.
+
+This command is a parser keyword. For details, check
+https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-different-stages"#
+    }
+
+    fn is_parser_keyword(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let var_id = call
+            .positional_nth(0)
+            .expect("checked through parser")
+            .as_var()
+            .expect("internal error: missing variable");
+
+        let keyword_expr = call
+            .positional_nth(1)
+            .expect("checked through parser")
+            .as_keyword()
+            .expect("internal error: missing keyword");
+
+        let rhs = eval_expression_with_input(
+            engine_state,
+            stack,
+            keyword_expr,
+            input,
+            call.redirect_stdout,
+            call.redirect_stderr,
+        )?;
+
+        stack.add_var(var_id, rhs.into_value(call.head));
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a new parse-time constant",
+                example: "const x = 10",
+                result: None,
+            },
+            Example {
+                description: "Use a constant in an expression",
+                example: "const plist = [a b c]; $plist | length",
+                result: Some(Value::test_int(3)),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Const {})
+    }
+}
@@ -1,6 +1,11 @@
+use nu_engine::{eval_expression_with_input, CallExt};
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Value};
+use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Value,
+};
+use std::time::Instant;
 
 #[derive(Clone)]
 pub struct Debug;
@@ -15,11 +20,19 @@ impl Command for Debug {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("debug").category(Category::Core).switch(
-            "raw",
-            "Prints the raw value representation",
-            Some('r'),
-        )
+        Signature::build("debug")
+            .category(Category::Core)
+            .switch("raw", "Prints the raw value representation", Some('r'))
+            .switch(
+                "profile",
+                "Profile the block instead of printing values: run it and report the time spent in each pipeline element",
+                Some('p'),
+            )
+            .optional(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "the block to profile (only used with --profile)",
+            )
     }
 
     fn run(
@@ -33,6 +46,10 @@ impl Command for Debug {
         let config = stack.get_config().unwrap_or_default();
         let raw = call.has_flag("raw");
 
+        if call.has_flag("profile") {
+            return run_profile(engine_state, stack, call, input);
+        }
+
         input.map(
             move |x| {
                 if raw {
@@ -70,10 +87,83 @@ impl Command for Debug {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Profile each pipeline element of a block",
+                example: "debug --profile { echo [1 2 3] | math sum }",
+                result: None,
+            },
         ]
     }
 }
 
+// Evaluate the given block one pipeline element at a time and report how long each element took.
+//
+// Streams are collected after each element so that lazy work is attributed to the element that
+// produces it, rather than to whatever consumes it later.
+fn run_profile(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+
+    let capture_block: Option<CaptureBlock> = call.opt(engine_state, stack, 0)?;
+    let capture_block = match capture_block {
+        Some(capture_block) => capture_block,
+        None => {
+            return Err(ShellError::MissingParameter(
+                "block to profile".into(),
+                head,
+            ));
+        }
+    };
+
+    let block = engine_state.get_block(capture_block.block_id);
+    let mut stack = stack.captures_to_stack(&capture_block.captures);
+
+    let mut input = input;
+    let mut output = vec![];
+
+    for (pipeline_idx, pipeline) in block.pipelines.iter().enumerate() {
+        for elem in pipeline.expressions.iter() {
+            let source =
+                String::from_utf8_lossy(engine_state.get_span_contents(&elem.span)).to_string();
+
+            let start_time = Instant::now();
+            input = eval_expression_with_input(engine_state, &mut stack, elem, input, true, false)?;
+            let value = input.into_value(elem.span);
+            let duration = start_time.elapsed();
+
+            if let Value::Error { error } = value {
+                return Err(error);
+            }
+            input = value.into_pipeline_data();
+
+            output.push(Value::Record {
+                cols: vec!["pipeline".into(), "source".into(), "duration".into()],
+                vals: vec![
+                    Value::Int {
+                        val: pipeline_idx as i64,
+                        span: head,
+                    },
+                    Value::String {
+                        val: source,
+                        span: head,
+                    },
+                    Value::Duration {
+                        val: duration.as_nanos() as i64,
+                        span: head,
+                    },
+                ],
+                span: head,
+            });
+        }
+    }
+
+    Ok(output.into_pipeline_data(engine_state.ctrlc.clone()))
+}
+
 #[cfg(test)]
 mod test {
     #[test]
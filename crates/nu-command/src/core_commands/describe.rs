@@ -44,11 +44,23 @@ impl Command for Describe {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Describe the type of a string",
-            example: "'hello' | describe",
-            result: Some(Value::test_string("string")),
-        }]
+        vec![
+            Example {
+                description: "Describe the type of a string",
+                example: "'hello' | describe",
+                result: Some(Value::test_string("string")),
+            },
+            Example {
+                description: "Describe the structure of a record",
+                example: "{shell: nu, uwu: true} | describe",
+                result: Some(Value::test_string("record<shell: string, uwu: bool>")),
+            },
+            Example {
+                description: "Describe the structure of a table",
+                example: "[[name size]; [nu 100]] | describe",
+                result: Some(Value::test_string("table<name: string, size: int>")),
+            },
+        ]
     }
 }
 
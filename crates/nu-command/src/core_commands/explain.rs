@@ -0,0 +1,218 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, Expr, Expression};
+use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Explain;
+
+impl Command for Explain {
+    fn name(&self) -> &str {
+        "explain"
+    }
+
+    fn usage(&self) -> &str {
+        "Explain how a block would be interpreted, without running it."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("explain")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "the block to explain",
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let capture_block: CaptureBlock = call.req(engine_state, stack, 0)?;
+        let block = engine_state.get_block(capture_block.block_id);
+
+        let mut rows = vec![];
+
+        for (pipeline_idx, pipeline) in block.pipelines.iter().enumerate() {
+            for (element_idx, expr) in pipeline.expressions.iter().enumerate() {
+                rows.push(explain_expression(
+                    engine_state,
+                    expr,
+                    pipeline_idx,
+                    element_idx,
+                    call.head,
+                ));
+            }
+        }
+
+        Ok(Value::List {
+            vals: rows,
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Explain how a pipeline of two commands will run",
+                example: "explain { ls | sort-by name }",
+                result: None,
+            },
+            Example {
+                description: "Show the inferred types of the arguments to a command",
+                example: "explain { echo 1 2 } | get args.0",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Describe one element of a pipeline: its command (if it is one), the text it
+/// was parsed from, its inferred output type, and its arguments
+fn explain_expression(
+    engine_state: &EngineState,
+    expr: &Expression,
+    pipeline_idx: usize,
+    element_idx: usize,
+    head: Span,
+) -> Value {
+    let (command, signature, args) = if let Expr::Call(call) = &expr.expr {
+        let decl = engine_state.get_decl(call.decl_id);
+        let signature = decl.signature();
+
+        let mut args = vec![];
+        for positional in call.positional_iter() {
+            args.push(explain_argument(
+                engine_state,
+                "positional",
+                positional,
+                head,
+            ));
+        }
+        for (name, _, value) in call.named_iter() {
+            if let Some(value) = value {
+                args.push(explain_argument(engine_state, &name.item, value, head));
+            }
+        }
+
+        (
+            decl.name().to_string(),
+            signature.call_signature(),
+            Value::List {
+                vals: args,
+                span: head,
+            },
+        )
+    } else {
+        (
+            "expression".to_string(),
+            String::new(),
+            Value::List {
+                vals: vec![],
+                span: head,
+            },
+        )
+    };
+
+    Value::Record {
+        cols: vec![
+            "pipeline".to_string(),
+            "element".to_string(),
+            "command".to_string(),
+            "signature".to_string(),
+            "content".to_string(),
+            "type".to_string(),
+            "args".to_string(),
+        ],
+        vals: vec![
+            Value::Int {
+                val: pipeline_idx as i64,
+                span: head,
+            },
+            Value::Int {
+                val: element_idx as i64,
+                span: head,
+            },
+            Value::String {
+                val: command,
+                span: head,
+            },
+            Value::String {
+                val: signature,
+                span: head,
+            },
+            Value::String {
+                val: String::from_utf8_lossy(engine_state.get_span_contents(&expr.span))
+                    .to_string(),
+                span: head,
+            },
+            Value::String {
+                val: expr.ty.to_string(),
+                span: head,
+            },
+            args,
+        ],
+        span: head,
+    }
+}
+
+fn explain_argument(
+    engine_state: &EngineState,
+    arg_type: &str,
+    expr: &Expression,
+    head: Span,
+) -> Value {
+    Value::Record {
+        cols: vec![
+            "arg_type".to_string(),
+            "content".to_string(),
+            "type".to_string(),
+            "span_start".to_string(),
+            "span_end".to_string(),
+        ],
+        vals: vec![
+            Value::String {
+                val: arg_type.to_string(),
+                span: head,
+            },
+            Value::String {
+                val: String::from_utf8_lossy(engine_state.get_span_contents(&expr.span))
+                    .to_string(),
+                span: head,
+            },
+            Value::String {
+                val: expr.ty.to_string(),
+                span: head,
+            },
+            Value::Int {
+                val: expr.span.start as i64,
+                span: head,
+            },
+            Value::Int {
+                val: expr.span.end as i64,
+                span: head,
+            },
+        ],
+        span: head,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Explain {})
+    }
+}
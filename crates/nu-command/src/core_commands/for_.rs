@@ -2,8 +2,8 @@ use nu_engine::{eval_block, eval_expression, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, Signature, Span, SyntaxShape,
-    Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -91,7 +91,7 @@ https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-
             Value::List { vals, .. } => Ok(vals
                 .into_iter()
                 .enumerate()
-                .map(move |(idx, x)| {
+                .map_while(move |(idx, x)| {
                     stack.with_env(&orig_env_vars, &orig_env_hidden);
 
                     stack.add_var(
@@ -122,8 +122,10 @@ https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-
                         redirect_stdout,
                         redirect_stderr,
                     ) {
-                        Ok(pipeline_data) => pipeline_data.into_value(head),
-                        Err(error) => Value::Error { error },
+                        Ok(pipeline_data) => Some(pipeline_data.into_value(head)),
+                        Err(ShellError::Break(_)) => None,
+                        Err(ShellError::Continue(_)) => Some(Value::Nothing { span: head }),
+                        Err(error) => Some(Value::Error { error }),
                     }
                 })
                 .filter(|x| !x.is_nothing())
@@ -131,7 +133,7 @@ https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-
             Value::Range { val, .. } => Ok(val
                 .into_range_iter(ctrlc.clone())?
                 .enumerate()
-                .map(move |(idx, x)| {
+                .map_while(move |(idx, x)| {
                     stack.with_env(&orig_env_vars, &orig_env_hidden);
 
                     stack.add_var(
@@ -162,8 +164,10 @@ https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-
                         redirect_stdout,
                         redirect_stderr,
                     ) {
-                        Ok(pipeline_data) => pipeline_data.into_value(head),
-                        Err(error) => Value::Error { error },
+                        Ok(pipeline_data) => Some(pipeline_data.into_value(head)),
+                        Err(ShellError::Break(_)) => None,
+                        Err(ShellError::Continue(_)) => Some(Value::Nothing { span: head }),
+                        Err(error) => Some(Value::Error { error }),
                     }
                 })
                 .filter(|x| !x.is_nothing())
@@ -171,14 +175,19 @@ https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-
             x => {
                 stack.add_var(var_id, x);
 
-                eval_block(
+                match eval_block(
                     &engine_state,
                     &mut stack,
                     &block,
                     PipelineData::new(head),
                     redirect_stdout,
                     redirect_stderr,
-                )
+                ) {
+                    Err(ShellError::Break(_)) | Err(ShellError::Continue(_)) => {
+                        Ok(PipelineData::new(head))
+                    }
+                    other => other,
+                }
             }
         }
     }
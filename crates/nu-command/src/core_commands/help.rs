@@ -2,10 +2,10 @@ use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
     span, Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
-    ShellError, Signature, Spanned, SyntaxShape, Value,
+    ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
 
-use nu_engine::{get_full_help, CallExt};
+use nu_engine::{generate_docs, get_full_help, CallExt};
 
 use std::borrow::Borrow;
 
@@ -91,83 +91,7 @@ fn help(
 
     if let Some(f) = find {
         let search_string = f.item.to_lowercase();
-        let mut found_cmds_vec = Vec::new();
-
-        for decl_id in commands {
-            let mut cols = vec![];
-            let mut vals = vec![];
-
-            let decl = engine_state.get_decl(decl_id);
-            let sig = decl.signature().update_from_command(decl.borrow());
-
-            let key = sig.name;
-            let usage = sig.usage;
-            let search_terms = sig.search_terms;
-            let matches_term = if !search_terms.is_empty() {
-                search_terms
-                    .iter()
-                    .any(|term| term.to_lowercase().contains(&search_string))
-            } else {
-                false
-            };
-
-            if key.to_lowercase().contains(&search_string)
-                || usage.to_lowercase().contains(&search_string)
-                || matches_term
-            {
-                cols.push("name".into());
-                vals.push(Value::String {
-                    val: key,
-                    span: head,
-                });
-
-                cols.push("category".into());
-                vals.push(Value::String {
-                    val: sig.category.to_string(),
-                    span: head,
-                });
-
-                cols.push("is_plugin".into());
-                vals.push(Value::Bool {
-                    val: decl.is_plugin().is_some(),
-                    span: head,
-                });
-
-                cols.push("is_custom".into());
-                vals.push(Value::Bool {
-                    val: decl.get_block_id().is_some(),
-                    span: head,
-                });
-
-                cols.push("is_keyword".into());
-                vals.push(Value::Bool {
-                    val: decl.is_parser_keyword(),
-                    span: head,
-                });
-
-                cols.push("usage".into());
-                vals.push(Value::String {
-                    val: usage,
-                    span: head,
-                });
-
-                cols.push("search_terms".into());
-                vals.push(if search_terms.is_empty() {
-                    Value::nothing(head)
-                } else {
-                    Value::String {
-                        val: search_terms.join(", "),
-                        span: head,
-                    }
-                });
-
-                found_cmds_vec.push(Value::Record {
-                    cols,
-                    vals,
-                    span: head,
-                });
-            }
-        }
+        let found_cmds_vec = search_commands(engine_state, &search_string, head);
 
         return Ok(found_cmds_vec
             .into_iter()
@@ -175,6 +99,10 @@ fn help(
     }
 
     if !rest.is_empty() {
+        if rest[0].item == "generate_docs" {
+            return Ok(generate_docs(engine_state, stack, head).into_pipeline_data());
+        }
+
         let mut found_cmds_vec = Vec::new();
 
         if rest[0].item == "commands" {
@@ -271,10 +199,20 @@ fn help(
                 }
                 .into_pipeline_data())
             } else {
-                Err(ShellError::CommandNotFound(span(&[
-                    rest[0].span,
-                    rest[rest.len() - 1].span,
-                ])))
+                // no command with that name; fall back to a full-text search
+                // so `help <term>` still turns up related commands
+                let found_cmds_vec = search_commands(engine_state, &name.to_lowercase(), head);
+
+                if !found_cmds_vec.is_empty() {
+                    Ok(found_cmds_vec
+                        .into_iter()
+                        .into_pipeline_data(engine_state.ctrlc.clone()))
+                } else {
+                    Err(ShellError::CommandNotFound(span(&[
+                        rest[0].span,
+                        rest[rest.len() - 1].span,
+                    ])))
+                }
             }
         }
     } else {
@@ -308,3 +246,88 @@ You can also learn more at https://www.nushell.sh/book/"#;
         .into_pipeline_data())
     }
 }
+
+fn search_commands(engine_state: &EngineState, search_string: &str, head: Span) -> Vec<Value> {
+    let commands = engine_state.get_decl_ids_sorted(false);
+    let mut found_cmds_vec = Vec::new();
+
+    for decl_id in commands {
+        let mut cols = vec![];
+        let mut vals = vec![];
+
+        let decl = engine_state.get_decl(decl_id);
+        let sig = decl.signature().update_from_command(decl.borrow());
+
+        let key = sig.name;
+        let usage = sig.usage;
+        let extra_usage = sig.extra_usage;
+        let search_terms = sig.search_terms;
+        let matches_term = if !search_terms.is_empty() {
+            search_terms
+                .iter()
+                .any(|term| term.to_lowercase().contains(search_string))
+        } else {
+            false
+        };
+
+        if key.to_lowercase().contains(search_string)
+            || usage.to_lowercase().contains(search_string)
+            || extra_usage.to_lowercase().contains(search_string)
+            || matches_term
+        {
+            cols.push("name".into());
+            vals.push(Value::String {
+                val: key,
+                span: head,
+            });
+
+            cols.push("category".into());
+            vals.push(Value::String {
+                val: sig.category.to_string(),
+                span: head,
+            });
+
+            cols.push("is_plugin".into());
+            vals.push(Value::Bool {
+                val: decl.is_plugin().is_some(),
+                span: head,
+            });
+
+            cols.push("is_custom".into());
+            vals.push(Value::Bool {
+                val: decl.get_block_id().is_some(),
+                span: head,
+            });
+
+            cols.push("is_keyword".into());
+            vals.push(Value::Bool {
+                val: decl.is_parser_keyword(),
+                span: head,
+            });
+
+            cols.push("usage".into());
+            vals.push(Value::String {
+                val: usage,
+                span: head,
+            });
+
+            cols.push("search_terms".into());
+            vals.push(if search_terms.is_empty() {
+                Value::nothing(head)
+            } else {
+                Value::String {
+                    val: search_terms.join(", "),
+                    span: head,
+                }
+            });
+
+            found_cmds_vec.push(Value::Record {
+                cols,
+                vals,
+                span: head,
+            });
+        }
+    }
+
+    found_cmds_vec
+}
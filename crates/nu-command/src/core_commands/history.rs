@@ -1,8 +1,12 @@
+#[cfg(feature = "sqlite")]
+use chrono::{Local, TimeZone};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Value,
 };
+#[cfg(feature = "sqlite")]
+use rusqlite::{params, Connection};
 
 const NEWLINE_ESCAPE_CODE: &str = "<\\n>";
 
@@ -31,11 +35,17 @@ impl Command for History {
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         _input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let head = call.head;
+        let config = stack.get_config()?;
+
+        if config.history_file_format == "sqlite" {
+            return run_sqlite_history(engine_state, call, None);
+        }
+
         if let Some(config_path) = nu_path::config_dir() {
             let clear = call.has_flag("clear");
             let ctrlc = engine_state.ctrlc.clone();
@@ -100,3 +110,127 @@ impl Command for History {
         ]
     }
 }
+
+/// Read the structured (sqlite) history, optionally restricted to a single
+/// session, and return one record per entry
+#[cfg(feature = "sqlite")]
+pub(super) fn run_sqlite_history(
+    engine_state: &EngineState,
+    call: &Call,
+    session_id: Option<i64>,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let ctrlc = engine_state.ctrlc.clone();
+
+    let mut history_path = nu_path::config_dir().ok_or(ShellError::FileNotFound(head))?;
+    history_path.push("nushell");
+    history_path.push("history.sqlite3");
+
+    if call.has_flag("clear") {
+        let _ = std::fs::remove_file(history_path);
+        return Ok(PipelineData::new(head));
+    }
+
+    let connection = Connection::open(&history_path).map_err(|_| ShellError::FileNotFound(head))?;
+
+    let query = if session_id.is_some() {
+        "SELECT command_line, start_timestamp, duration_ms, exit_status, cwd, session_id
+         FROM history WHERE session_id = ?1 ORDER BY id"
+    } else {
+        "SELECT command_line, start_timestamp, duration_ms, exit_status, cwd, session_id
+         FROM history ORDER BY id"
+    };
+
+    let mut statement = connection
+        .prepare(query)
+        .map_err(|err| ShellError::IOError(err.to_string()))?;
+
+    let rows = if let Some(session_id) = session_id {
+        statement.query(params![session_id])
+    } else {
+        statement.query([])
+    };
+    let mut rows = rows.map_err(|err| ShellError::IOError(err.to_string()))?;
+
+    let mut entries = vec![];
+    let mut index = 0;
+    while let Ok(Some(row)) = rows.next() {
+        let command: String = row.get(0).unwrap_or_default();
+        let start_timestamp: i64 = row.get(1).unwrap_or_default();
+        let duration_ms: i64 = row.get(2).unwrap_or_default();
+        let exit_status: i64 = row.get(3).unwrap_or_default();
+        let cwd: String = row.get(4).unwrap_or_default();
+        let entry_session_id: i64 = row.get(5).unwrap_or_default();
+
+        let start = Local
+            .timestamp_opt(
+                start_timestamp / 1000,
+                (start_timestamp % 1000) as u32 * 1_000_000,
+            )
+            .single()
+            .map(|t| Value::Date {
+                val: t.with_timezone(t.offset()),
+                span: head,
+            })
+            .unwrap_or(Value::Nothing { span: head });
+
+        entries.push(Value::Record {
+            cols: vec![
+                "command".to_string(),
+                "start".to_string(),
+                "duration".to_string(),
+                "exit_status".to_string(),
+                "cwd".to_string(),
+                "session_id".to_string(),
+                "index".to_string(),
+            ],
+            vals: vec![
+                Value::String {
+                    val: command,
+                    span: head,
+                },
+                start,
+                Value::Duration {
+                    val: duration_ms * 1_000_000,
+                    span: head,
+                },
+                Value::Int {
+                    val: exit_status,
+                    span: head,
+                },
+                Value::String {
+                    val: cwd,
+                    span: head,
+                },
+                Value::Int {
+                    val: entry_session_id,
+                    span: head,
+                },
+                Value::Int {
+                    val: index,
+                    span: head,
+                },
+            ],
+            span: head,
+        });
+        index += 1;
+    }
+
+    Ok(entries.into_iter().into_pipeline_data(ctrlc))
+}
+
+/// Builds without the sqlite backend keep the commands and the config knob,
+/// but point the user at the missing feature instead of a missing file
+#[cfg(not(feature = "sqlite"))]
+pub(super) fn run_sqlite_history(
+    _engine_state: &EngineState,
+    call: &Call,
+    _session_id: Option<i64>,
+) -> Result<PipelineData, ShellError> {
+    Err(ShellError::SpannedLabeledErrorHelp(
+        "Structured history is not available in this build".into(),
+        "compiled without the sqlite feature".into(),
+        call.head,
+        "rebuild nushell with the 'sqlite' feature, or set $config.history_file_format back to 'plaintext'".into(),
+    ))
+}
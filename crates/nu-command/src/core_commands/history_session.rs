@@ -0,0 +1,51 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature};
+
+use super::history::run_sqlite_history;
+
+#[derive(Clone)]
+pub struct HistorySession;
+
+impl Command for HistorySession {
+    fn name(&self) -> &str {
+        "history session"
+    }
+
+    fn usage(&self) -> &str {
+        "Get the command history of the current shell session"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("history session").category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let config = stack.get_config()?;
+
+        if config.history_file_format != "sqlite" {
+            return Err(ShellError::SpannedLabeledErrorHelp(
+                "Per-session history requires the sqlite history backend".into(),
+                "only recorded by the sqlite backend".into(),
+                call.head,
+                "set $config.history_file_format to 'sqlite' to record structured history".into(),
+            ));
+        }
+
+        run_sqlite_history(engine_state, call, Some(engine_state.history_session_id))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "history session",
+            description: "Show the commands run in this session",
+            result: None,
+        }]
+    }
+}
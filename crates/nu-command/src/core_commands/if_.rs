@@ -2,8 +2,7 @@ use nu_engine::{eval_block, eval_expression, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, FromValue, IntoPipelineData, PipelineData, ShellError, Signature,
-    SyntaxShape, Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -58,11 +57,12 @@ https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-
         match &result {
             Value::Bool { val, .. } => {
                 if *val {
+                    // Blocks run on the caller's stack, so variable assignments and
+                    // environment changes inside them stay visible afterwards
                     let block = engine_state.get_block(then_block.block_id);
-                    let mut stack = stack.captures_to_stack(&then_block.captures);
                     eval_block(
                         engine_state,
-                        &mut stack,
+                        stack,
                         block,
                         input,
                         call.redirect_stdout,
@@ -71,14 +71,10 @@ https://www.nushell.sh/book/thinking_in_nushell.html#parsing-and-evaluation-are-
                 } else if let Some(else_case) = else_case {
                     if let Some(else_expr) = else_case.as_keyword() {
                         if let Some(block_id) = else_expr.as_block() {
-                            let result = eval_expression(engine_state, stack, else_expr)?;
-                            let else_block: CaptureBlock = FromValue::from_value(&result)?;
-
-                            let mut stack = stack.captures_to_stack(&else_block.captures);
                             let block = engine_state.get_block(block_id);
                             eval_block(
                                 engine_state,
-                                &mut stack,
+                                stack,
                                 block,
                                 input,
                                 call.redirect_stdout,
@@ -0,0 +1,92 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct IsAdmin;
+
+impl Command for IsAdmin {
+    fn name(&self) -> &str {
+        "is-admin"
+    }
+
+    fn usage(&self) -> &str {
+        "Check if nushell is running with administrator or root privileges"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("is-admin").category(Category::Core)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["root", "elevated", "privileges"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::Bool {
+            val: is_root(),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Return 'iamroot' if nushell is running with admin/root privileges, and 'iamnotroot' if not.",
+            example: r#"if is-admin { echo "iamroot" } else { echo "iamnotroot" }"#,
+            result: None,
+        }]
+    }
+}
+
+/// Returns `true` if the effective user is root.
+#[cfg(unix)]
+fn is_root() -> bool {
+    users::get_effective_uid() == 0
+}
+
+/// Returns `true` if the current process runs with an elevated token.
+#[cfg(windows)]
+fn is_root() -> bool {
+    use std::mem;
+    use winapi::shared::minwindef::{DWORD, LPVOID};
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::processthreadsapi::{GetCurrentProcess, OpenProcessToken};
+    use winapi::um::securitybaseapi::GetTokenInformation;
+    use winapi::um::winnt::{TokenElevation, HANDLE, TOKEN_ELEVATION, TOKEN_QUERY};
+
+    let mut handle: HANDLE = std::ptr::null_mut();
+    let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+    let mut size = mem::size_of::<TOKEN_ELEVATION>() as DWORD;
+    let mut elevated = false;
+
+    // SAFETY: `handle`, `elevation` and `size` outlive the calls that borrow
+    // them, and the token handle is closed before returning.
+    unsafe {
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut handle) != 0
+            && GetTokenInformation(
+                handle,
+                TokenElevation,
+                &mut elevation as *mut TOKEN_ELEVATION as LPVOID,
+                size,
+                &mut size,
+            ) != 0
+        {
+            elevated = elevation.TokenIsElevated != 0;
+        }
+
+        if !handle.is_null() {
+            CloseHandle(handle);
+        }
+    }
+
+    elevated
+}
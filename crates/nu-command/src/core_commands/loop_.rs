@@ -0,0 +1,90 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape};
+use std::sync::atomic::Ordering;
+
+#[derive(Clone)]
+pub struct Loop;
+
+impl Command for Loop {
+    fn name(&self) -> &str {
+        "loop"
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block in a loop until break."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("loop")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "block to run in a loop",
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let capture_block: CaptureBlock = call.req(engine_state, stack, 0)?;
+
+        // Run the block on the caller's stack so changes to the environment
+        // survive from one iteration to the next
+        let block = engine_state.get_block(capture_block.block_id);
+
+        loop {
+            if let Some(ctrlc) = &engine_state.ctrlc {
+                if ctrlc.load(Ordering::SeqCst) {
+                    break;
+                }
+            }
+
+            match eval_block(
+                engine_state,
+                stack,
+                block,
+                PipelineData::new(call.head),
+                call.redirect_stdout,
+                call.redirect_stderr,
+            ) {
+                Err(ShellError::Break(_)) => break,
+                Err(ShellError::Continue(_)) => continue,
+                Err(err) => return Err(err),
+                Ok(pipeline) => {
+                    // Discard the block's output each time around, like a
+                    // semicolon-terminated statement
+                    pipeline.into_value(call.head);
+                }
+            }
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Run a block until break is hit",
+            example: r#"loop { break }"#,
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Loop {})
+    }
+}
@@ -0,0 +1,232 @@
+use nu_engine::{eval_block, eval_expression};
+use nu_protocol::ast::{Call, Expression, MatchPattern, Pattern};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value, VarId,
+};
+
+#[derive(Clone)]
+pub struct Match;
+
+impl Command for Match {
+    fn name(&self) -> &str {
+        "match"
+    }
+
+    fn usage(&self) -> &str {
+        "Conditionally run a block on a matched value."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("match")
+            .required("value", SyntaxShape::Any, "value to check")
+            .required(
+                "match_block",
+                SyntaxShape::MatchBlock,
+                "block of matches to check",
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let value_expr = call.positional_nth(0).expect("checked through parser");
+        let block = call.positional_nth(1).expect("checked through parser");
+
+        let value = eval_expression(engine_state, stack, value_expr)?;
+
+        let mut found = None;
+
+        if let Expression {
+            expr: nu_protocol::ast::Expr::MatchBlock(matches),
+            ..
+        } = block
+        {
+            for (pattern, expr) in matches {
+                let mut match_variables = vec![];
+                if match_pattern(engine_state, stack, pattern, &value, &mut match_variables)? {
+                    for (var_id, val) in match_variables {
+                        stack.add_var(var_id, val);
+                    }
+
+                    if let Some(guard) = &pattern.guard {
+                        match eval_expression(engine_state, stack, guard)? {
+                            Value::Bool { val, .. } => {
+                                if !val {
+                                    continue;
+                                }
+                            }
+                            other => {
+                                return Err(ShellError::CantConvert(
+                                    "bool".into(),
+                                    other.get_type().to_string(),
+                                    guard.span,
+                                ))
+                            }
+                        }
+                    }
+
+                    found = Some(expr);
+                    break;
+                }
+            }
+        }
+
+        if let Some(expr) = found {
+            if let Some(block_id) = expr.as_block() {
+                // Blocks run on the caller's stack so the pattern variables are visible
+                let block = engine_state.get_block(block_id);
+                eval_block(
+                    engine_state,
+                    stack,
+                    block,
+                    input,
+                    call.redirect_stdout,
+                    call.redirect_stderr,
+                )
+            } else {
+                eval_expression(engine_state, stack, expr).map(|x| x.into_pipeline_data())
+            }
+        } else {
+            Ok(PipelineData::new(call.head))
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Match on a literal value",
+                example: "match 3 { 1 => 'one', 2 => 'two', 3 => 'three' }",
+                result: Some(Value::test_string("three")),
+            },
+            Example {
+                description: "Match against a range",
+                example: "match 42 { 0..9 => 'digit', _ => 'multiple digits' }",
+                result: Some(Value::test_string("multiple digits")),
+            },
+            Example {
+                description: "Match on a value's type",
+                example: "match [1, 2, 3] { int => 'int', list => 'list', _ => 'other' }",
+                result: Some(Value::test_string("list")),
+            },
+            Example {
+                description: "Destructure a record",
+                example: "match {name: bob, age: 31} { {name: $n} => { $n } }",
+                result: Some(Value::test_string("bob")),
+            },
+            Example {
+                description: "Destructure a list with a rest pattern",
+                example: "match [1, 2, 3] { [$head, ..$tail] => { $tail | math sum } }",
+                result: Some(Value::test_int(5)),
+            },
+            Example {
+                description: "Use a guard on a match arm",
+                example: "match 5 { $x if $x > 3 => 'big', _ => 'small' }",
+                result: Some(Value::test_string("big")),
+            },
+        ]
+    }
+}
+
+fn match_pattern(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    pattern: &MatchPattern,
+    value: &Value,
+    matches: &mut Vec<(VarId, Value)>,
+) -> Result<bool, ShellError> {
+    match &pattern.pattern {
+        Pattern::IgnoreValue => Ok(true),
+        Pattern::Variable(var_id) => {
+            matches.push((*var_id, value.clone()));
+            Ok(true)
+        }
+        Pattern::Type(ty) => {
+            let value_ty = value.get_type();
+            Ok(match ty {
+                Type::List(_) => matches!(value_ty, Type::List(_) | Type::Table(_)),
+                Type::Record(_) => matches!(value_ty, Type::Record(_)),
+                Type::Table(_) => matches!(value_ty, Type::Table(_)),
+                Type::Number => value_ty.is_numeric(),
+                _ => &value_ty == ty,
+            })
+        }
+        Pattern::Value(expr) => {
+            let pattern_value = eval_expression(engine_state, stack, expr)?;
+
+            if let Value::Range { val, .. } = &pattern_value {
+                Ok(val.contains(value))
+            } else {
+                Ok(&pattern_value == value)
+            }
+        }
+        Pattern::Record(fields) => {
+            if let Value::Record { cols, vals, .. } = value {
+                for (field, pattern) in fields {
+                    match cols.iter().position(|col| col == field) {
+                        Some(idx) => {
+                            if !match_pattern(engine_state, stack, pattern, &vals[idx], matches)? {
+                                return Ok(false);
+                            }
+                        }
+                        None => return Ok(false),
+                    }
+                }
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+        Pattern::List(items) => {
+            if let Value::List { vals, .. } = value {
+                for (idx, pattern) in items.iter().enumerate() {
+                    match &pattern.pattern {
+                        Pattern::IgnoreRest => return Ok(true),
+                        Pattern::Rest(var_id) => {
+                            let rest = vals[idx.min(vals.len())..].to_vec();
+                            matches.push((
+                                *var_id,
+                                Value::List {
+                                    vals: rest,
+                                    span: pattern.span,
+                                },
+                            ));
+                            return Ok(true);
+                        }
+                        _ => match vals.get(idx) {
+                            Some(val) => {
+                                if !match_pattern(engine_state, stack, pattern, val, matches)? {
+                                    return Ok(false);
+                                }
+                            }
+                            None => return Ok(false),
+                        },
+                    }
+                }
+                // Without a rest pattern, extra values mean the list doesn't match
+                Ok(vals.len() <= items.len())
+            } else {
+                Ok(false)
+            }
+        }
+        Pattern::Rest(_) | Pattern::IgnoreRest | Pattern::Garbage => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Match {})
+    }
+}
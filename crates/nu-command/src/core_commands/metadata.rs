@@ -84,6 +84,15 @@ impl Command for Metadata {
                                 span: head,
                             })
                         }
+                        PipelineMetadata {
+                            data_source: DataSource::FilePath(path),
+                        } => {
+                            cols.push("source".into());
+                            vals.push(Value::String {
+                                val: path.to_string_lossy().to_string(),
+                                span: head,
+                            })
+                        }
                     }
                 }
 
@@ -146,6 +155,15 @@ fn build_metadata_record(arg: &Value, metadata: &Option<PipelineMetadata>, head:
                     span: head,
                 })
             }
+            PipelineMetadata {
+                data_source: DataSource::FilePath(path),
+            } => {
+                cols.push("source".into());
+                vals.push(Value::String {
+                    val: path.to_string_lossy().to_string(),
+                    span: head,
+                })
+            }
         }
     }
 
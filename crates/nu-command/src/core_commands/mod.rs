@@ -1,4 +1,8 @@
 mod alias;
+mod ast;
+mod break_;
+mod const_;
+mod continue_;
 mod debug;
 mod def;
 mod def_env;
@@ -6,28 +10,44 @@ mod describe;
 mod do_;
 mod echo;
 mod error_make;
+mod explain;
 mod export;
 mod export_alias;
 mod export_def;
 mod export_def_env;
 mod export_env;
 mod export_extern;
+mod export_module;
 mod extern_;
 mod for_;
 mod help;
 mod hide;
+mod hide_env;
 mod history;
+mod history_session;
 mod if_;
 mod ignore;
+mod is_admin;
 mod let_;
+mod loop_;
+mod match_;
 mod metadata;
 mod module;
+mod mut_;
+mod nu_check;
+mod return_;
+mod scope;
 mod source;
 mod tutor;
 mod use_;
 mod version;
+mod while_;
 
 pub use alias::Alias;
+pub use ast::Ast;
+pub use break_::Break;
+pub use const_::Const;
+pub use continue_::Continue;
 pub use debug::Debug;
 pub use def::Def;
 pub use def_env::DefEnv;
@@ -35,26 +55,38 @@ pub use describe::Describe;
 pub use do_::Do;
 pub use echo::Echo;
 pub use error_make::ErrorMake;
+pub use explain::Explain;
 pub use export::ExportCommand;
 pub use export_alias::ExportAlias;
 pub use export_def::ExportDef;
 pub use export_def_env::ExportDefEnv;
 pub use export_env::ExportEnv;
 pub use export_extern::ExportExtern;
+pub use export_module::ExportModule;
 pub use extern_::Extern;
 pub use for_::For;
 pub use help::Help;
 pub use hide::Hide;
+pub use hide_env::HideEnv;
 pub use history::History;
+pub use history_session::HistorySession;
 pub use if_::If;
 pub use ignore::Ignore;
+pub use is_admin::IsAdmin;
 pub use let_::Let;
+pub use loop_::Loop;
+pub use match_::Match;
 pub use metadata::Metadata;
 pub use module::Module;
+pub use mut_::Mut;
+pub use nu_check::NuCheck;
+pub use return_::Return;
+pub use scope::{Scope, ScopeAliases, ScopeCommands, ScopeModules, ScopeVariables};
 pub use source::Source;
 pub use tutor::Tutor;
 pub use use_::Use;
 pub use version::Version;
+pub use while_::While;
 #[cfg(feature = "plugin")]
 mod register;
 
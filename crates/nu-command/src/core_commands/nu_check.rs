@@ -0,0 +1,99 @@
+use nu_engine::{current_dir, CallExt};
+use nu_parser::{parse, parse_module_block};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack, StateWorkingSet};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct NuCheck;
+
+impl Command for NuCheck {
+    fn name(&self) -> &str {
+        "nu-check"
+    }
+
+    fn usage(&self) -> &str {
+        "Validate nushell source without running it."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns true if the file parses cleanly, false otherwise. Use --debug to also print the parse error."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["syntax", "lint", "parse"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("nu-check")
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "path of the script or module to check",
+            )
+            .switch("as-module", "parse the file as a module", Some('m'))
+            .switch("debug", "print the parse error, if any", Some('d'))
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let as_module: bool = call.has_flag("as-module");
+        let is_debug: bool = call.has_flag("debug");
+
+        let cwd = current_dir(engine_state, stack)?;
+        let path_buf = nu_path::expand_path_with(&path.item, cwd);
+
+        let contents = std::fs::read(&path_buf).map_err(|_| ShellError::FileNotFound(path.span))?;
+
+        let mut working_set = StateWorkingSet::new(engine_state);
+
+        let err = if as_module {
+            let start = working_set.next_span_start();
+            working_set.add_file(path.item.clone(), &contents);
+            let end = working_set.next_span_start();
+
+            let (_, _, err) = parse_module_block(&mut working_set, Span { start, end }, &[]);
+            err
+        } else {
+            let (_, err) = parse(&mut working_set, Some(&path.item), &contents, false, &[]);
+            err
+        };
+
+        if is_debug {
+            if let Some(err) = &err {
+                eprintln!("error: {:?}", err);
+            }
+        }
+
+        Ok(Value::Bool {
+            val: err.is_none(),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check if a script parses cleanly",
+                example: "nu-check script.nu",
+                result: None,
+            },
+            Example {
+                description: "Check a module, printing the error on failure",
+                example: "nu-check --as-module --debug mymodule.nu",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,57 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+
+#[derive(Clone)]
+pub struct Return;
+
+impl Command for Return {
+    fn name(&self) -> &str {
+        "return"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("return")
+            .optional("return_value", SyntaxShape::Any, "optional value to return")
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Return early from a custom command."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let return_value: Option<Value> = call.opt(engine_state, stack, 0)?;
+
+        if let Some(value) = return_value {
+            Err(ShellError::Return(call.head, Box::new(value)))
+        } else {
+            Err(ShellError::Return(
+                call.head,
+                Box::new(Value::nothing(call.head)),
+            ))
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Return early",
+                example: r#"def foo [] { return }"#,
+                result: None,
+            },
+            Example {
+                description: "Return a value from a custom command",
+                example: r#"def foo [] { return 10 }"#,
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,24 @@
+mod aliases;
+mod commands;
+mod modules;
+mod scope_;
+mod variables;
+
+use nu_protocol::{ShellError, Span, Value};
+
+pub use aliases::ScopeAliases;
+pub use commands::ScopeCommands;
+pub use modules::ScopeModules;
+pub use scope_::Scope;
+pub use variables::ScopeVariables;
+
+/// Pull one column out of the record produced by `create_scope`
+fn get_scope_column(scope: Value, column: &str, span: Span) -> Result<Value, ShellError> {
+    scope.get_data_by_key(column).ok_or_else(|| {
+        ShellError::SpannedLabeledError(
+            "Missing scope information".into(),
+            format!("no {} in the scope record", column),
+            span,
+        )
+    })
+}
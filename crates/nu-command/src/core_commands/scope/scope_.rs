@@ -0,0 +1,41 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Scope;
+
+impl Command for Scope {
+    fn name(&self) -> &str {
+        "scope"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Commands for inspecting what is in scope"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        Ok(Value::String {
+            val: get_full_help(&Scope.signature(), &Scope.examples(), engine_state, stack),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["introspection", "debug"]
+    }
+}
@@ -0,0 +1,44 @@
+use nu_engine::create_scope;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, Signature,
+};
+
+#[derive(Clone)]
+pub struct ScopeVariables;
+
+impl Command for ScopeVariables {
+    fn name(&self) -> &str {
+        "scope variables"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Output info on the variables in the current scope"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let span = call.head;
+        let scope = create_scope(engine_state, stack, span)?;
+
+        Ok(super::get_scope_column(scope, "vars", span)?.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the variables in the current scope",
+            example: "scope variables",
+            result: None,
+        }]
+    }
+}
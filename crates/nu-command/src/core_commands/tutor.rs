@@ -94,6 +94,11 @@ fn tutor(
             ],
             expression_tutor(),
         ),
+        (vec!["pipeline", "pipelines"], pipeline_tutor()),
+        (
+            vec!["def", "custom", "custom-command", "custom-commands"],
+            custom_command_tutor(),
+        ),
         (vec!["echo"], echo_tutor()),
         (vec!["each", "iteration", "iter"], each_tutor()),
         (
@@ -345,6 +350,70 @@ with all of the data coming in from the pipeline in one place.
 "#
 }
 
+fn pipeline_tutor() -> &'static str {
+    r#"
+Nushell is built around the pipeline: commands connected with the '|'
+symbol, where the output of one command becomes the input of the next.
+
+Unlike traditional shells, the data flowing through a Nushell pipeline
+is structured. For example:
+```
+ls | where size > 1kb | sort-by modified
+```
+Here, `ls` produces a table, `where` keeps only the rows whose size
+column is over a kilobyte, and `sort-by` reorders those rows. Each step
+works with the table produced by the previous step.
+
+A pipeline can end by displaying the data (the default) or by passing it
+to a command that writes it somewhere, like `save`:
+```
+ls | to json | save files.json
+```
+To see what kind of data is flowing through a pipeline at any point, you
+can insert the `describe` command:
+```
+ls | get name | describe
+```
+You can learn more about working with tables by running:
+```
+tutor tables
+```
+"#
+}
+
+fn custom_command_tutor() -> &'static str {
+    r#"
+You can create your own commands in Nushell with the `def` keyword. A
+custom command has a name, a list of parameters, and a block to run:
+```
+def greet [name] {
+    echo "hello" $name
+}
+```
+Once defined, the command can be called like any built-in command:
+```
+greet nushell
+```
+Parameters can also be given types and optional flags:
+```
+def greet [name: string, --shout] {
+    if $shout {
+        echo ($"hello ($name)" | str upcase)
+    } else {
+        echo $"hello ($name)"
+    }
+}
+```
+Custom commands participate in pipelines just like built-in commands do,
+so anything they produce can flow into the next command.
+
+You can learn more about blocks by running:
+```
+tutor blocks
+```
+"#
+}
+
 fn block_tutor() -> &'static str {
     r#"
 Blocks are a special form of expression that hold code to be run at a later
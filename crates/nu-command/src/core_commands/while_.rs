@@ -0,0 +1,108 @@
+use nu_engine::{eval_block, eval_expression, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+use std::sync::atomic::Ordering;
+
+#[derive(Clone)]
+pub struct While;
+
+impl Command for While {
+    fn name(&self) -> &str {
+        "while"
+    }
+
+    fn usage(&self) -> &str {
+        "Conditionally run a block in a loop."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("while")
+            .required("cond", SyntaxShape::Expression, "condition to check")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "block to run while the condition is true",
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let cond = call.positional_nth(0).expect("checked through parser");
+        let capture_block: CaptureBlock = call.req(engine_state, stack, 1)?;
+
+        let block = engine_state.get_block(capture_block.block_id);
+
+        loop {
+            if let Some(ctrlc) = &engine_state.ctrlc {
+                if ctrlc.load(Ordering::SeqCst) {
+                    break;
+                }
+            }
+
+            let result = eval_expression(engine_state, stack, cond)?;
+            match &result {
+                Value::Bool { val, .. } => {
+                    if !*val {
+                        break;
+                    }
+
+                    // Run the block on the caller's stack so changes to the
+                    // environment survive from one iteration to the next
+                    match eval_block(
+                        engine_state,
+                        stack,
+                        block,
+                        PipelineData::new(call.head),
+                        call.redirect_stdout,
+                        call.redirect_stderr,
+                    ) {
+                        Err(ShellError::Break(_)) => break,
+                        Err(ShellError::Continue(_)) => continue,
+                        Err(err) => return Err(err),
+                        Ok(pipeline) => {
+                            // Discard the block's output each time around, like
+                            // a semicolon-terminated statement
+                            pipeline.into_value(call.head);
+                        }
+                    }
+                }
+                x => {
+                    return Err(ShellError::CantConvert(
+                        "bool".into(),
+                        x.get_type().to_string(),
+                        result.span()?,
+                    ))
+                }
+            }
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Loop while a condition is true",
+            example: r#"let-env x = "0"; while $env.x == "0" { let-env x = "1" }"#,
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(While {})
+    }
+}
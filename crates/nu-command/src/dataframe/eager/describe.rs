@@ -47,6 +47,7 @@ impl Command for DescribeDF {
                         "descriptor".to_string(),
                         vec![
                             Value::test_string("count"),
+                            Value::test_string("null_count"),
                             Value::test_string("sum"),
                             Value::test_string("mean"),
                             Value::test_string("median"),
@@ -62,6 +63,7 @@ impl Command for DescribeDF {
                         "a (i64)".to_string(),
                         vec![
                             Value::test_float(2.0),
+                            Value::test_float(0.0),
                             Value::test_float(2.0),
                             Value::test_float(1.0),
                             Value::test_float(1.0),
@@ -77,6 +79,7 @@ impl Command for DescribeDF {
                         "b (i64)".to_string(),
                         vec![
                             Value::test_float(2.0),
+                            Value::test_float(0.0),
                             Value::test_float(2.0),
                             Value::test_float(1.0),
                             Value::test_float(1.0),
@@ -151,6 +154,7 @@ fn command(
         .collect::<Vec<Option<String>>>();
     let mut labels = vec![
         Some("count".to_string()),
+        Some("null_count".to_string()),
         Some("sum".to_string()),
         Some("mean".to_string()),
         Some("median".to_string()),
@@ -174,6 +178,8 @@ fn command(
         .map(|col| {
             let count = col.len() as f64;
 
+            let null_count = col.null_count() as f64;
+
             let sum = col
                 .sum_as_series()
                 .cast(&DataType::Float64)
@@ -230,7 +236,7 @@ fn command(
                     _ => None,
                 });
 
-            let mut descriptors = vec![Some(count), sum, mean, median, std, min];
+            let mut descriptors = vec![Some(count), Some(null_count), sum, mean, median, std, min];
             descriptors.append(&mut quantiles);
             descriptors.push(max);
 
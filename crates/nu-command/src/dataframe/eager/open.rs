@@ -1,14 +1,17 @@
 use super::super::values::NuDataFrame;
+use super::to_df::str_to_dtype;
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
 };
 
 use std::{fs::File, io::BufReader, path::PathBuf};
 
-use polars::prelude::{CsvEncoding, CsvReader, JsonReader, ParquetReader, SerReader};
+use polars::prelude::{
+    CsvEncoding, CsvReader, Field, JsonReader, ParquetReader, Schema, SerReader,
+};
 
 #[derive(Clone)]
 pub struct OpenDataFrame;
@@ -58,15 +61,28 @@ impl Command for OpenDataFrame {
                 "Columns to be selected from csv file. CSV and Parquet file",
                 None,
             )
+            .named(
+                "dtypes",
+                SyntaxShape::Record,
+                "Column name and dtype pairs that override the inferred schema. CSV file",
+                None,
+            )
             .category(Category::Custom("dataframe".into()))
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Takes a file name and creates a dataframe",
-            example: "dfr open test.csv",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Takes a file name and creates a dataframe",
+                example: "dfr open test.csv",
+                result: None,
+            },
+            Example {
+                description: "Takes a file name and creates a dataframe, overriding column dtypes",
+                example: "dfr open test.csv --dtypes {id: str, total: f64}",
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -151,6 +167,28 @@ fn from_json(
     })
 }
 
+fn schema_from_record(dtypes: &Value) -> Result<Schema, ShellError> {
+    match dtypes {
+        Value::Record { cols, vals, .. } => {
+            let fields = cols
+                .iter()
+                .zip(vals.iter())
+                .map(|(col, val)| {
+                    let dtype = str_to_dtype(&val.as_string()?, val.span()?)?;
+                    Ok(Field::new(col, dtype))
+                })
+                .collect::<Result<Vec<Field>, ShellError>>()?;
+
+            Ok(Schema::new(fields))
+        }
+        _ => Err(ShellError::SpannedLabeledError(
+            "Incorrect type for dtypes".into(),
+            "expected a record of column name and dtype pairs".into(),
+            dtypes.span()?,
+        )),
+    }
+}
+
 fn from_csv(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -162,6 +200,7 @@ fn from_csv(
     let infer_schema: Option<usize> = call.get_flag(engine_state, stack, "infer-schema")?;
     let skip_rows: Option<usize> = call.get_flag(engine_state, stack, "skip-rows")?;
     let columns: Option<Vec<String>> = call.get_flag(engine_state, stack, "columns")?;
+    let dtypes: Option<Value> = call.get_flag(engine_state, stack, "dtypes")?;
 
     let csv_reader = CsvReader::from_path(&file.item)
         .map_err(|e| {
@@ -209,6 +248,14 @@ fn from_csv(
         Some(columns) => csv_reader.with_columns(Some(columns)),
     };
 
+    let schema = dtypes
+        .map(|dtypes| schema_from_record(&dtypes))
+        .transpose()?;
+    let csv_reader = match &schema {
+        None => csv_reader,
+        Some(schema) => csv_reader.with_dtypes(Some(schema)),
+    };
+
     csv_reader.finish().map_err(|e| {
         ShellError::SpannedLabeledError(
             "Parquet reader error".into(),
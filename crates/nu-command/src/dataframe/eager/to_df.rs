@@ -1,10 +1,12 @@
 use super::super::values::{Column, NuDataFrame};
 
+use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, ShellError, Signature, Span, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
+use polars::prelude::{DataFrame, DataType, Series, TimeUnit};
 
 #[derive(Clone)]
 pub struct ToDataFrame;
@@ -19,7 +21,14 @@ impl Command for ToDataFrame {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build(self.name()).category(Category::Custom("dataframe".into()))
+        Signature::build(self.name())
+            .named(
+                "dtype",
+                SyntaxShape::String,
+                "datatype the columns are casted to (i64, f64, str, bool, ...)",
+                Some('d'),
+            )
+            .category(Category::Custom("dataframe".into()))
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -84,6 +93,23 @@ impl Command for ToDataFrame {
                     .into_value(Span::test_data()),
                 ),
             },
+            Example {
+                description:
+                    "Takes a list of numbers and creates a dataframe with an explicit dtype",
+                example: "[1 2 3] | dfr to-df --dtype f64",
+                result: Some(
+                    NuDataFrame::try_from_columns(vec![Column::new(
+                        "0".to_string(),
+                        vec![
+                            Value::test_float(1.0),
+                            Value::test_float(2.0),
+                            Value::test_float(3.0),
+                        ],
+                    )])
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
             Example {
                 description: "Takes a list of booleans and creates a dataframe",
                 example: "[true true false] | dfr to-df",
@@ -105,13 +131,74 @@ impl Command for ToDataFrame {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        NuDataFrame::try_from_iter(input.into_iter())
-            .map(|df| PipelineData::Value(NuDataFrame::into_value(df, call.head), None))
+        let dtype: Option<Spanned<String>> = call.get_flag(engine_state, stack, "dtype")?;
+
+        let df = NuDataFrame::try_from_iter(input.into_iter())?;
+
+        let df = match dtype {
+            Some(dtype) => cast_columns(df, &dtype)?,
+            None => df,
+        };
+
+        Ok(PipelineData::Value(
+            NuDataFrame::into_value(df, call.head),
+            None,
+        ))
+    }
+}
+
+fn cast_columns(df: NuDataFrame, dtype: &Spanned<String>) -> Result<NuDataFrame, ShellError> {
+    let dtype_value = str_to_dtype(&dtype.item, dtype.span)?;
+
+    let casted = df
+        .as_ref()
+        .get_columns()
+        .iter()
+        .map(|col| {
+            col.cast(&dtype_value).map_err(|e| {
+                ShellError::SpannedLabeledError(
+                    "Error casting column".into(),
+                    e.to_string(),
+                    dtype.span,
+                )
+            })
+        })
+        .collect::<Result<Vec<Series>, ShellError>>()?;
+
+    DataFrame::new(casted)
+        .map_err(|e| {
+            ShellError::SpannedLabeledError("Dataframe Error".into(), e.to_string(), dtype.span)
+        })
+        .map(NuDataFrame::new)
+}
+
+pub(super) fn str_to_dtype(dtype: &str, span: Span) -> Result<DataType, ShellError> {
+    match dtype {
+        "bool" => Ok(DataType::Boolean),
+        "u8" => Ok(DataType::UInt8),
+        "u16" => Ok(DataType::UInt16),
+        "u32" => Ok(DataType::UInt32),
+        "u64" => Ok(DataType::UInt64),
+        "i8" => Ok(DataType::Int8),
+        "i16" => Ok(DataType::Int16),
+        "i32" => Ok(DataType::Int32),
+        "i64" => Ok(DataType::Int64),
+        "f32" => Ok(DataType::Float32),
+        "f64" => Ok(DataType::Float64),
+        "str" => Ok(DataType::Utf8),
+        "date" => Ok(DataType::Date),
+        "datetime" => Ok(DataType::Datetime(TimeUnit::Milliseconds, None)),
+        "time" => Ok(DataType::Time),
+        _ => Err(ShellError::SpannedLabeledError(
+            "Incorrect datatype".into(),
+            format!("unsupported datatype {}", dtype),
+            span,
+        )),
     }
 }
 
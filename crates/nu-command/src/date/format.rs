@@ -1,9 +1,9 @@
-use chrono::Local;
+use chrono::{Local, Locale};
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
 
 use super::utils::parse_date_from_string;
@@ -19,6 +19,12 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("date format")
             .switch("list", "lists strftime cheatsheet", Some('l'))
+            .named(
+                "locale",
+                SyntaxShape::String,
+                "locale used for month and day names and the %x/%c/%X specifiers (defaults to $env.LC_ALL, $env.LC_TIME or $env.LANG)",
+                None,
+            )
             .optional(
                 "format string",
                 SyntaxShape::String,
@@ -47,10 +53,12 @@ impl Command for SubCommand {
         }
 
         let format = call.opt::<String>(engine_state, stack, 0)?;
+        let locale_flag: Option<Spanned<String>> = call.get_flag(engine_state, stack, "locale")?;
+        let locale = get_locale(locale_flag)?;
 
         input.map(
             move |value| match &format {
-                Some(format) => format_helper(value, format.as_str(), head),
+                Some(format) => format_helper(value, format.as_str(), locale, head),
                 None => format_helper_rfc2822(value, head),
             },
             engine_state.ctrlc.clone(),
@@ -82,14 +90,19 @@ impl Command for SubCommand {
                 example: r#""2021-10-22 20:00:12 +01:00" | date format "%Y-%m-%d""#,
                 result: None,
             },
+            Example {
+                description: "Format the current date with the preferred format of a given locale.",
+                example: r#"date format --locale de_DE '%A, %x'"#,
+                result: None,
+            },
         ]
     }
 }
 
-fn format_helper(value: Value, formatter: &str, span: Span) -> Value {
+fn format_helper(value: Value, formatter: &str, locale: Locale, span: Span) -> Value {
     match value {
         Value::Date { val, span: _ } => Value::String {
-            val: val.format(formatter).to_string(),
+            val: val.format_localized(formatter, locale).to_string(),
             span,
         },
         Value::String {
@@ -99,7 +112,7 @@ fn format_helper(value: Value, formatter: &str, span: Span) -> Value {
             let dt = parse_date_from_string(&val, val_span);
             match dt {
                 Ok(x) => Value::String {
-                    val: x.format(formatter).to_string(),
+                    val: x.format_localized(formatter, locale).to_string(),
                     span,
                 },
                 Err(e) => e,
@@ -108,7 +121,10 @@ fn format_helper(value: Value, formatter: &str, span: Span) -> Value {
         Value::Nothing { span: _ } => {
             let dt = Local::now();
             Value::String {
-                val: dt.with_timezone(dt.offset()).format(formatter).to_string(),
+                val: dt
+                    .with_timezone(dt.offset())
+                    .format_localized(formatter, locale)
+                    .to_string(),
                 span,
             }
         }
@@ -118,6 +134,37 @@ fn format_helper(value: Value, formatter: &str, span: Span) -> Value {
     }
 }
 
+/// Pick the locale for the `%x`/`%c` style specifiers and spelled-out names,
+/// either from the `--locale` flag or from the usual environment variables.
+fn get_locale(locale_flag: Option<Spanned<String>>) -> Result<Locale, ShellError> {
+    match locale_flag {
+        Some(locale) => {
+            // "en_US.UTF-8" style values carry an encoding suffix the locale
+            // table doesn't know about.
+            let name = locale.item.split('.').next().unwrap_or_default();
+            name.try_into().map_err(|_| {
+                ShellError::SpannedLabeledError(
+                    "Invalid locale".into(),
+                    format!("unknown locale {}", locale.item),
+                    locale.span,
+                )
+            })
+        }
+        None => {
+            let name = std::env::var("LC_ALL")
+                .or_else(|_| std::env::var("LC_TIME"))
+                .or_else(|_| std::env::var("LANG"))
+                .unwrap_or_default();
+
+            Ok(name
+                .split('.')
+                .next()
+                .and_then(|name| name.try_into().ok())
+                .unwrap_or(Locale::en_US))
+        }
+    }
+}
+
 fn format_helper_rfc2822(value: Value, span: Span) -> Value {
     match value {
         Value::Date { val, span: _ } => Value::String {
@@ -46,6 +46,11 @@ impl Command for SubCommand {
                 example: r#""2021-10-22 20:00:12 +01:00" | date humanize"#,
                 result: None,
             },
+            Example {
+                description: "Print a 'humanized' format for a duration, relative to now.",
+                example: "2day | date humanize",
+                result: None,
+            },
         ]
     }
 }
@@ -76,6 +81,10 @@ fn helper(value: Value, head: Span) -> Value {
             val: humanize_date(val),
             span: head,
         },
+        Value::Duration { val, span: _ } => Value::String {
+            val: HumanTime::from(chrono::Duration::nanoseconds(val)).to_string(),
+            span: head,
+        },
         _ => Value::Error {
             error: ShellError::DatetimeParseError(head),
         },
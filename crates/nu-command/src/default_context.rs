@@ -26,6 +26,10 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
         // Core
         bind_command! {
             Alias,
+            Ast,
+            Break,
+            Const,
+            Continue,
             Debug,
             Def,
             DefEnv,
@@ -34,26 +38,42 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             Du,
             Echo,
             ErrorMake,
+            Explain,
             ExportAlias,
             ExportCommand,
             ExportDef,
             ExportDefEnv,
             ExportEnv,
             ExportExtern,
+            ExportModule,
             Extern,
             For,
             Help,
             Hide,
+            HideEnv,
             History,
+            HistorySession,
             If,
             Ignore,
+            IsAdmin,
             Let,
+            Loop,
+            Match,
             Metadata,
             Module,
+            Mut,
+            NuCheck,
+            Return,
+            Scope,
+            ScopeAliases,
+            ScopeCommands,
+            ScopeModules,
+            ScopeVariables,
             Source,
             Tutor,
             Use,
             Version,
+            While,
         };
 
         // Filters
@@ -119,6 +139,11 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             Zip,
         };
 
+        // Charting
+        bind_command! {
+            Histogram,
+        };
+
         // Path
         bind_command! {
             Path,
@@ -146,6 +171,17 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
         #[cfg(feature = "which-support")]
         bind_command! { Which };
 
+        // Bytes
+        bind_command! {
+            Bytes,
+            BytesAdd,
+            BytesAt,
+            BytesCollect,
+            BytesLen,
+            BytesReplace,
+            BytesStartsWith,
+        };
+
         // Strings
         bind_command! {
             BuildString,
@@ -153,6 +189,7 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             Decode,
             DetectColumns,
             Format,
+            FileSize,
             Parse,
             Size,
             Split,
@@ -166,6 +203,8 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             StrContains,
             StrDowncase,
             StrEndswith,
+            StrExpand,
+            StrJoin,
             StrReplace,
             StrIndexOf,
             StrKebabCase,
@@ -192,6 +231,8 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             Open,
             Rm,
             Save,
+            Start,
+            Tee,
             Touch,
             Glob,
         };
@@ -204,6 +245,7 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             Clear,
             KeybindingsDefault,
             Input,
+            InputListen,
             KeybindingsListen,
             Keybindings,
             Kill,
@@ -212,6 +254,9 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             TermSize,
         };
 
+        #[cfg(feature = "clipboard-cli")]
+        bind_command! { Clip, Paste };
+
         // Date
         bind_command! {
             Date,
@@ -259,6 +304,7 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             ToJson,
             ToMd,
             ToNuon,
+            ToText,
             ToToml,
             ToTsv,
             ToCsv,
@@ -288,11 +334,16 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
             IntoDuration,
             IntoFilesize,
             IntoInt,
+            IntoRecord,
             IntoString,
         };
 
         // Env
         bind_command! {
+            ConfigEnv,
+            ConfigMeta,
+            ConfigNu,
+            ConfigReset,
             Env,
             LetEnv,
             LoadEnv,
@@ -345,6 +396,7 @@ pub fn create_default_context(cwd: impl AsRef<Path>) -> EngineState {
         bind_command! {
             Cal,
             Seq,
+            SeqChar,
             SeqDate,
         };
 
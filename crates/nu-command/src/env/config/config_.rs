@@ -0,0 +1,46 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct ConfigMeta;
+
+impl Command for ConfigMeta {
+    fn name(&self) -> &str {
+        "config"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Edit nushell configuration files"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &ConfigMeta.signature(),
+                &ConfigMeta.examples(),
+                engine_state,
+                stack,
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["configuration", "settings"]
+    }
+}
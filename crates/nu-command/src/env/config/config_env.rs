@@ -0,0 +1,52 @@
+use super::utils::{config_file_path, create_from_template_if_missing, open_in_editor};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, Signature,
+};
+
+#[derive(Clone)]
+pub struct ConfigEnv;
+
+impl Command for ConfigEnv {
+    fn name(&self) -> &str {
+        "config env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Edit nu environment configuration file"
+    }
+
+    fn extra_usage(&self) -> &str {
+        "If the file does not exist yet, it is created from the default template first."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "allow user to open and update nu env",
+            example: "config env",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let path = config_file_path("env.nu", call.head)?;
+        create_from_template_if_missing(
+            &path,
+            include_str!("../../../../../docs/sample_config/default_env.nu"),
+            call.head,
+        )?;
+
+        open_in_editor(engine_state, stack, &path, call.head, input)
+    }
+}
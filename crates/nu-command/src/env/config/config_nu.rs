@@ -0,0 +1,52 @@
+use super::utils::{config_file_path, create_from_template_if_missing, open_in_editor};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, Signature,
+};
+
+#[derive(Clone)]
+pub struct ConfigNu;
+
+impl Command for ConfigNu {
+    fn name(&self) -> &str {
+        "config nu"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Edit nu configuration file"
+    }
+
+    fn extra_usage(&self) -> &str {
+        "If the file does not exist yet, it is created from the default template first."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "allow user to open and update nu config",
+            example: "config nu",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let path = config_file_path("config.nu", call.head)?;
+        create_from_template_if_missing(
+            &path,
+            include_str!("../../../../../docs/sample_config/default_config.nu"),
+            call.head,
+        )?;
+
+        open_in_editor(engine_state, stack, &path, call.head, input)
+    }
+}
@@ -0,0 +1,108 @@
+use super::utils::config_file_path;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Span,
+};
+use std::io::Write;
+
+#[derive(Clone)]
+pub struct ConfigReset;
+
+impl Command for ConfigReset {
+    fn name(&self) -> &str {
+        "config reset"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .switch("nu", "reset only the nu config (config.nu)", None)
+            .switch("env", "reset only the env config (env.nu)", None)
+            .switch("without-backup", "do not make a backup", Some('w'))
+            .category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Reset nushell environment configurations to default, and saves old config files in the config location as oldconfig.nu and oldenv.nu"
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "reset nushell configuration files",
+            example: "config reset",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let only_nu = call.has_flag("nu");
+        let only_env = call.has_flag("env");
+        let no_backup = call.has_flag("without-backup");
+        let span = call.head;
+
+        if !only_env {
+            reset_file(
+                "config.nu",
+                "oldconfig.nu",
+                include_str!("../../../../../docs/sample_config/default_config.nu"),
+                no_backup,
+                span,
+            )?;
+        }
+
+        if !only_nu {
+            reset_file(
+                "env.nu",
+                "oldenv.nu",
+                include_str!("../../../../../docs/sample_config/default_env.nu"),
+                no_backup,
+                span,
+            )?;
+        }
+
+        Ok(PipelineData::new(span))
+    }
+}
+
+fn reset_file(
+    filename: &str,
+    backup_filename: &str,
+    default_contents: &str,
+    no_backup: bool,
+    span: Span,
+) -> Result<(), ShellError> {
+    let path = config_file_path(filename, span)?;
+
+    if path.exists() && !no_backup {
+        let backup_path = config_file_path(backup_filename, span)?;
+        if std::fs::rename(&path, &backup_path).is_err() {
+            return Err(ShellError::SpannedLabeledError(
+                "Could not make a backup".into(),
+                format!("failed to rename {} to {}", filename, backup_filename),
+                span,
+            ));
+        }
+    }
+
+    let mut file = std::fs::File::create(&path).map_err(|err| {
+        ShellError::SpannedLabeledError(
+            format!("Could not create {}", filename),
+            err.to_string(),
+            span,
+        )
+    })?;
+
+    write!(file, "{}", default_contents).map_err(|err| {
+        ShellError::SpannedLabeledError(
+            format!("Could not write to {}", filename),
+            err.to_string(),
+            span,
+        )
+    })
+}
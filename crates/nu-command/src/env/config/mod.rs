@@ -0,0 +1,10 @@
+mod config_;
+mod config_env;
+mod config_nu;
+mod config_reset;
+mod utils;
+
+pub use config_::ConfigMeta;
+pub use config_env::ConfigEnv;
+pub use config_nu::ConfigNu;
+pub use config_reset::ConfigReset;
@@ -0,0 +1,108 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use nu_protocol::{
+    engine::{EngineState, Stack},
+    ShellError, Span,
+};
+
+use crate::ExternalCommand;
+
+pub(super) const NUSHELL_FOLDER: &str = "nushell";
+
+/// Return the path of a file inside the nushell config directory
+pub(super) fn config_file_path(filename: &str, span: Span) -> Result<PathBuf, ShellError> {
+    let mut path = nu_path::config_dir().ok_or_else(|| {
+        ShellError::SpannedLabeledError(
+            "Could not find config directory".into(),
+            "not found".into(),
+            span,
+        )
+    })?;
+
+    path.push(NUSHELL_FOLDER);
+    path.push(filename);
+
+    Ok(path)
+}
+
+/// Write `default_contents` to the config file if it does not exist yet
+pub(super) fn create_from_template_if_missing(
+    path: &PathBuf,
+    default_contents: &str,
+    span: Span,
+) -> Result<(), ShellError> {
+    if path.exists() {
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| {
+            ShellError::SpannedLabeledError(
+                "Could not create config directory".into(),
+                err.to_string(),
+                span,
+            )
+        })?;
+    }
+
+    let mut output = File::create(path).map_err(|err| {
+        ShellError::SpannedLabeledError(
+            "Could not create config file".into(),
+            err.to_string(),
+            span,
+        )
+    })?;
+
+    write!(output, "{}", default_contents).map_err(|err| {
+        ShellError::SpannedLabeledError("Could not write config file".into(), err.to_string(), span)
+    })
+}
+
+/// Find an editor to spawn, from $env.EDITOR or $env.VISUAL
+fn get_editor(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    span: Span,
+) -> Result<String, ShellError> {
+    for var in ["EDITOR", "VISUAL"] {
+        if let Some(value) = stack.get_env_var(engine_state, var) {
+            let editor = value.as_string()?;
+            if !editor.is_empty() {
+                return Ok(editor);
+            }
+        }
+    }
+
+    Err(ShellError::SpannedLabeledError(
+        "No editor configured".into(),
+        "set $env.EDITOR or $env.VISUAL to the editor to use".into(),
+        span,
+    ))
+}
+
+/// Open `path` in the user's editor, blocking until it exits
+pub(super) fn open_in_editor(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    path: &PathBuf,
+    span: Span,
+    input: nu_protocol::PipelineData,
+) -> Result<nu_protocol::PipelineData, ShellError> {
+    let editor = get_editor(engine_state, stack, span)?;
+    let env_vars_str = nu_engine::env_to_strings(engine_state, stack)?;
+
+    let command = ExternalCommand {
+        name: nu_protocol::Spanned { item: editor, span },
+        args: vec![nu_protocol::Spanned {
+            item: path.to_string_lossy().to_string(),
+            span,
+        }],
+        redirect_stdout: false,
+        redirect_stderr: false,
+        env_vars: env_vars_str,
+    };
+
+    command.run_with_input(engine_state, stack, input)
+}
@@ -1,8 +1,10 @@
+mod config;
 mod env_command;
 mod let_env;
 mod load_env;
 mod with_env;
 
+pub use config::{ConfigEnv, ConfigMeta, ConfigNu, ConfigReset};
 pub use env_command::Env;
 pub use let_env::LetEnv;
 pub use load_env::LoadEnv;
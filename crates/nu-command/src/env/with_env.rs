@@ -4,7 +4,7 @@ use nu_engine::{eval_block, CallExt};
 use nu_protocol::{
     ast::Call,
     engine::{CaptureBlock, Command, EngineState, Stack},
-    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -56,6 +56,14 @@ impl Command for WithEnv {
                 example: r#"with-env [X Y W Z] { $env.X }"#,
                 result: Some(Value::test_string("Y")),
             },
+            Example {
+                description: "Set by key-value record",
+                example: r#"with-env {X: "Y", W: "Z"} { [$env.X $env.W] }"#,
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("Y"), Value::test_string("Z")],
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "Set by single row table",
                 example: r#"with-env [[X W]; [Y Z]] { $env.W }"#,
@@ -110,8 +118,15 @@ fn with_env(
                 for row in table.chunks(2) {
                     if row.len() == 2 {
                         env.insert(row[0].as_string()?, (&row[1]).clone());
+                    } else if row.len() == 1 {
+                        return Err(ShellError::CantConvert(
+                            "environment variable-value pair".into(),
+                            format!("odd number of environment variables ({})", table.len()),
+                            call.positional_nth(0)
+                                .expect("already checked through .req")
+                                .span,
+                        ));
                     }
-                    // TODO: else error?
                 }
             }
         }
@@ -70,6 +70,19 @@ impl Command for ViewSource {
                             arg_span,
                         ))
                     }
+                } else if let Some(alias_id) = engine_state.find_alias(val.as_bytes()) {
+                    // arg is an alias; the stored expansion spans point back at
+                    // the source the alias was defined with
+                    let contents: Vec<String> = engine_state
+                        .get_alias(alias_id)
+                        .iter()
+                        .map(|span| {
+                            String::from_utf8_lossy(engine_state.get_span_contents(span))
+                                .to_string()
+                        })
+                        .collect();
+
+                    Ok(Value::string(contents.join(" "), call.head).into_pipeline_data())
                 } else if let Some(overlay_id) = engine_state.find_overlay(val.as_bytes()) {
                     // arg is a module
                     let overlay = engine_state.get_overlay(overlay_id);
@@ -126,6 +139,14 @@ impl Command for ViewSource {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "View the source of an alias",
+                example: r#"alias hello = echo hi; view-source hello"#,
+                result: Some(Value::String {
+                    val: "echo hi".to_string(),
+                    span: Span::test_data(),
+                }),
+            },
             Example {
                 description: "View the source of a module",
                 example: r#"module mod-foo { export env FOO_ENV { 'BAZ' } }; view-source mod-foo"#,
@@ -73,7 +73,7 @@ impl Command for Mkdir {
             }
 
             if show_created_paths {
-                let val = format!("{:}", dir.to_string_lossy());
+                let val = dir.to_string_lossy().to_string();
                 stream.push_back(Value::String { val, span });
             }
         }
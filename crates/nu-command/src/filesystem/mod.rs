@@ -7,6 +7,8 @@ mod mv;
 mod open;
 mod rm;
 mod save;
+mod start;
+mod tee;
 mod touch;
 mod util;
 
@@ -19,4 +21,6 @@ pub use mv::Mv;
 pub use open::{BufferedReader, Open};
 pub use rm::Rm;
 pub use save::Save;
+pub use start::Start;
+pub use tee::Tee;
 pub use touch::Touch;
@@ -2,8 +2,8 @@ use nu_engine::{eval_block, get_full_help, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, RawStream, ShellError, Signature, Spanned,
-    SyntaxShape, Value,
+    Category, DataSource, Example, IntoPipelineData, PipelineData, PipelineMetadata, RawStream,
+    ShellError, Signature, Spanned, SyntaxShape, Value,
 };
 use std::io::{BufRead, BufReader, Read};
 
@@ -129,7 +129,9 @@ impl Command for Open {
                 stderr: None,
                 exit_code: None,
                 span: call_span,
-                metadata: None,
+                metadata: Some(PipelineMetadata {
+                    data_source: DataSource::FilePath(path.to_path_buf()),
+                }),
             };
 
             let ext = if raw {
@@ -2,11 +2,11 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
 use std::io::{BufWriter, Write};
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Clone)]
 pub struct Save;
@@ -25,6 +25,11 @@ impl Command for Save {
             .required("filename", SyntaxShape::Filepath, "the filename to use")
             .switch("raw", "save file as raw binary", Some('r'))
             .switch("append", "append input to the end of the file", None)
+            .switch(
+                "atomic",
+                "write to a temporary file and rename it over the target on success",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -37,6 +42,7 @@ impl Command for Save {
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let raw = call.has_flag("raw");
         let append = call.has_flag("append");
+        let atomic = call.has_flag("atomic");
 
         let span = call.head;
 
@@ -44,15 +50,29 @@ impl Command for Save {
         let arg_span = path.span;
         let path = Path::new(&path.item);
 
-        let file = match (append, path.exists()) {
+        let write_path = if atomic {
+            temp_path(path)
+        } else {
+            path.to_path_buf()
+        };
+
+        if atomic && append && path.exists() {
+            // The appended output has to land in the temporary file too,
+            // otherwise the rename would throw away the existing contents.
+            if let Err(err) = std::fs::copy(path, &write_path) {
+                return Err(ShellError::IOError(err.to_string()));
+            }
+        }
+
+        let file = match (append, write_path.exists()) {
             (true, true) => std::fs::OpenOptions::new()
                 .write(true)
                 .append(true)
-                .open(path),
-            _ => std::fs::File::create(path),
+                .open(&write_path),
+            _ => std::fs::File::create(&write_path),
         };
 
-        let mut file = match file {
+        let file = match file {
             Ok(file) => file,
             Err(err) => {
                 return Ok(PipelineData::Value(
@@ -75,22 +95,154 @@ impl Command for Save {
                 .map(|name| name.to_string_lossy().to_string())
         };
 
-        if let Some(ext) = ext {
-            let output = match engine_state.find_decl(format!("to {}", ext).as_bytes()) {
-                Some(converter_id) => {
-                    let output = engine_state.get_decl(converter_id).run(
-                        engine_state,
-                        stack,
-                        &Call::new(span),
-                        input,
-                    )?;
-
-                    output.into_value(span)
+        let result = write_pipeline(file, ext, engine_state, stack, call, input, span);
+
+        if atomic {
+            match &result {
+                Ok(_) => {
+                    // Interrupted saves leave the temporary file behind instead
+                    // of a truncated target.
+                    #[cfg(windows)]
+                    if path.exists() {
+                        if let Err(err) = std::fs::remove_file(path) {
+                            return Err(ShellError::IOError(err.to_string()));
+                        }
+                    }
+
+                    if let Err(err) = std::fs::rename(&write_path, path) {
+                        return Err(ShellError::IOError(err.to_string()));
+                    }
+                }
+                Err(_) => {
+                    let _ = std::fs::remove_file(&write_path);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save a string to foo.txt in current directory",
+                example: r#"echo 'save me' | save foo.txt"#,
+                result: None,
+            },
+            Example {
+                description: "Save a record to foo.json in current directory",
+                example: r#"echo { a: 1, b: 2 } | save foo.json"#,
+                result: None,
+            },
+            Example {
+                description: "Save a config without risking a truncated file on interruption",
+                example: r#"open config.toml | save --atomic config.toml"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(format!(".tmp-{}", std::process::id()));
+    path.with_file_name(name)
+}
+
+fn write_pipeline(
+    mut file: std::fs::File,
+    ext: Option<String>,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    span: Span,
+) -> Result<PipelineData, ShellError> {
+    if let Some(ext) = ext {
+        let output = match engine_state.find_decl(format!("to {}", ext).as_bytes()) {
+            Some(converter_id) => {
+                let output = engine_state.get_decl(converter_id).run(
+                    engine_state,
+                    stack,
+                    &Call::new(span),
+                    input,
+                )?;
+
+                output.into_value(span)
+            }
+            None => input.into_value(span),
+        };
+
+        match output {
+            Value::String { val, .. } => {
+                if let Err(err) = file.write_all(val.as_bytes()) {
+                    return Err(ShellError::IOError(err.to_string()));
                 }
-                None => input.into_value(span),
-            };
 
-            match output {
+                Ok(PipelineData::new(span))
+            }
+            Value::Binary { val, .. } => {
+                if let Err(err) = file.write_all(&val) {
+                    return Err(ShellError::IOError(err.to_string()));
+                }
+
+                Ok(PipelineData::new(span))
+            }
+            Value::List { vals, .. } => {
+                let val = vals
+                    .into_iter()
+                    .map(|it| it.as_string())
+                    .collect::<Result<Vec<String>, ShellError>>()?
+                    .join("\n")
+                    + "\n";
+
+                if let Err(err) = file.write_all(val.as_bytes()) {
+                    return Err(ShellError::IOError(err.to_string()));
+                }
+
+                Ok(PipelineData::new(span))
+            }
+            v => Err(ShellError::UnsupportedInput(
+                format!("{:?} not supported", v.get_type()),
+                span,
+            )),
+        }
+    } else {
+        match input {
+            PipelineData::ExternalStream { stdout: None, .. } => Ok(PipelineData::new(span)),
+            PipelineData::ExternalStream {
+                stdout: Some(mut stream),
+                ..
+            } => {
+                let mut writer = BufWriter::new(file);
+
+                stream
+                    .try_for_each(move |result| {
+                        let buf = match result {
+                            Ok(v) => match v {
+                                Value::String { val, .. } => val.into_bytes(),
+                                Value::Binary { val, .. } => val,
+                                _ => {
+                                    return Err(ShellError::UnsupportedInput(
+                                        format!("{:?} not supported", v.get_type()),
+                                        v.span()?,
+                                    ));
+                                }
+                            },
+                            Err(err) => return Err(err),
+                        };
+
+                        if let Err(err) = writer.write(&buf) {
+                            return Err(ShellError::IOError(err.to_string()));
+                        }
+                        Ok(())
+                    })
+                    .map(|_| PipelineData::new(span))
+            }
+            input => match input.into_value(span) {
                 Value::String { val, .. } => {
                     if let Err(err) = file.write_all(val.as_bytes()) {
                         return Err(ShellError::IOError(err.to_string()));
@@ -123,89 +275,7 @@ impl Command for Save {
                     format!("{:?} not supported", v.get_type()),
                     span,
                 )),
-            }
-        } else {
-            match input {
-                PipelineData::ExternalStream { stdout: None, .. } => Ok(PipelineData::new(span)),
-                PipelineData::ExternalStream {
-                    stdout: Some(mut stream),
-                    ..
-                } => {
-                    let mut writer = BufWriter::new(file);
-
-                    stream
-                        .try_for_each(move |result| {
-                            let buf = match result {
-                                Ok(v) => match v {
-                                    Value::String { val, .. } => val.into_bytes(),
-                                    Value::Binary { val, .. } => val,
-                                    _ => {
-                                        return Err(ShellError::UnsupportedInput(
-                                            format!("{:?} not supported", v.get_type()),
-                                            v.span()?,
-                                        ));
-                                    }
-                                },
-                                Err(err) => return Err(err),
-                            };
-
-                            if let Err(err) = writer.write(&buf) {
-                                return Err(ShellError::IOError(err.to_string()));
-                            }
-                            Ok(())
-                        })
-                        .map(|_| PipelineData::new(span))
-                }
-                input => match input.into_value(span) {
-                    Value::String { val, .. } => {
-                        if let Err(err) = file.write_all(val.as_bytes()) {
-                            return Err(ShellError::IOError(err.to_string()));
-                        }
-
-                        Ok(PipelineData::new(span))
-                    }
-                    Value::Binary { val, .. } => {
-                        if let Err(err) = file.write_all(&val) {
-                            return Err(ShellError::IOError(err.to_string()));
-                        }
-
-                        Ok(PipelineData::new(span))
-                    }
-                    Value::List { vals, .. } => {
-                        let val = vals
-                            .into_iter()
-                            .map(|it| it.as_string())
-                            .collect::<Result<Vec<String>, ShellError>>()?
-                            .join("\n")
-                            + "\n";
-
-                        if let Err(err) = file.write_all(val.as_bytes()) {
-                            return Err(ShellError::IOError(err.to_string()));
-                        }
-
-                        Ok(PipelineData::new(span))
-                    }
-                    v => Err(ShellError::UnsupportedInput(
-                        format!("{:?} not supported", v.get_type()),
-                        span,
-                    )),
-                },
-            }
-        }
-    }
-
-    fn examples(&self) -> Vec<Example> {
-        vec![
-            Example {
-                description: "Save a string to foo.txt in current directory",
-                example: r#"echo 'save me' | save foo.txt"#,
-                result: None,
             },
-            Example {
-                description: "Save a record to foo.json in current directory",
-                example: r#"echo { a: 1, b: 2 } | save foo.json"#,
-                result: None,
-            },
-        ]
+        }
     }
 }
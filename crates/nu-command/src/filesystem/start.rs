@@ -0,0 +1,114 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
+
+#[derive(Clone)]
+pub struct Start;
+
+impl Command for Start {
+    fn name(&self) -> &str {
+        "start"
+    }
+
+    fn usage(&self) -> &str {
+        "Open a file or url with the default application."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("start")
+            .required("path", SyntaxShape::String, "the file or url to open")
+            .named(
+                "application",
+                SyntaxShape::String,
+                "the application used for opening the file or url",
+                Some('a'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let application: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "application")?;
+
+        let mut command = match &application {
+            Some(application) => {
+                let mut command = std::process::Command::new(&application.item);
+                command.arg(&path.item);
+                command
+            }
+            None => default_opener(&path.item),
+        };
+
+        let status = command.status().map_err(|err| {
+            ShellError::ExternalCommand(
+                format!("Failed to open '{}'", path.item),
+                err.to_string(),
+                path.span,
+            )
+        })?;
+
+        if status.success() {
+            Ok(PipelineData::new(call.head))
+        } else {
+            Err(ShellError::ExternalCommand(
+                format!("Failed to open '{}'", path.item),
+                format!("opener exited with {}", status),
+                path.span,
+            ))
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Open a text file with the default text editor",
+                example: "start file.txt",
+                result: None,
+            },
+            Example {
+                description: "Open a url with the default browser",
+                example: "start https://www.nushell.sh",
+                result: None,
+            },
+            Example {
+                description: "Open a file with an application of your choice",
+                example: "start --application gimp file.png",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The platform's own way of opening something with its default application
+fn default_opener(path: &str) -> std::process::Command {
+    #[cfg(target_os = "macos")]
+    {
+        let mut command = std::process::Command::new("open");
+        command.arg(path);
+        command
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // An empty title argument keeps `start` from treating a quoted path
+        // as the window title
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", "", path]);
+        command
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let mut command = std::process::Command::new("xdg-open");
+        command.arg(path);
+        command
+    }
+}
@@ -0,0 +1,139 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, RawStream, ShellError, Signature, Spanned, SyntaxShape, Value,
+};
+use std::io::Write;
+use std::path::Path;
+
+#[derive(Clone)]
+pub struct Tee;
+
+impl Command for Tee {
+    fn name(&self) -> &str {
+        "tee"
+    }
+
+    fn usage(&self) -> &str {
+        "Write the pipeline to a file while passing it along unchanged."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("tee")
+            .required("filename", SyntaxShape::Filepath, "the filename to use")
+            .switch("append", "append input to the end of the file", Some('a'))
+            .category(Category::FileSystem)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save the middle of a pipeline to a file while still filtering it",
+                example: "ls | tee files.txt | where size > 10kb",
+                result: None,
+            },
+            Example {
+                description: "Append an external command's output to a log and keep paging it",
+                example: "cargo build | tee --append build.log",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let append = call.has_flag("append");
+        let span = call.head;
+
+        let path = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+        let arg_span = path.span;
+        let path = Path::new(&path.item);
+
+        let file = match (append, path.exists()) {
+            (true, true) => std::fs::OpenOptions::new()
+                .write(true)
+                .append(true)
+                .open(path),
+            _ => std::fs::File::create(path),
+        };
+
+        let mut file = match file {
+            Ok(file) => file,
+            Err(err) => {
+                return Err(ShellError::SpannedLabeledError(
+                    "Permission denied".into(),
+                    err.to_string(),
+                    arg_span,
+                ));
+            }
+        };
+
+        let ctrlc = engine_state.ctrlc.clone();
+
+        match input {
+            PipelineData::ExternalStream { stdout: None, .. } => Ok(input),
+            PipelineData::ExternalStream {
+                stdout: Some(stream),
+                stderr,
+                exit_code,
+                span: stream_span,
+                metadata,
+            } => {
+                // Copy each chunk to the file as it goes by instead of draining
+                // the stream, so the rest of the pipeline still sees the data.
+                let stream = stream.map(move |result| {
+                    let buf = match result? {
+                        Value::String { val, .. } => val.into_bytes(),
+                        Value::Binary { val, .. } => val,
+                        v => {
+                            return Err(ShellError::UnsupportedInput(
+                                format!("{:?} not supported", v.get_type()),
+                                span,
+                            ));
+                        }
+                    };
+
+                    file.write_all(&buf)
+                        .map_err(|err| ShellError::IOError(err.to_string()))?;
+
+                    Ok(buf)
+                });
+
+                Ok(PipelineData::ExternalStream {
+                    stdout: Some(RawStream::new(Box::new(stream), ctrlc, stream_span)),
+                    stderr,
+                    exit_code,
+                    span: stream_span,
+                    metadata,
+                })
+            }
+            input => {
+                let config = stack.get_config().unwrap_or_default();
+
+                Ok(input.map(
+                    move |value| {
+                        let text = value.into_string("\n", &config);
+
+                        if let Err(err) = file
+                            .write_all(text.as_bytes())
+                            .and_then(|_| file.write_all(b"\n"))
+                        {
+                            return Value::Error {
+                                error: ShellError::IOError(err.to_string()),
+                            };
+                        }
+
+                        value
+                    },
+                    ctrlc,
+                )?)
+            }
+        }
+    }
+}
@@ -18,14 +18,19 @@ impl Command for DropColumn {
         Signature::build(self.name())
             .optional(
                 "columns",
-                SyntaxShape::Int,
-                "starting from the end, the number of columns to remove",
+                SyntaxShape::Any,
+                "starting from the end, the number of columns to remove, or the name of a column to remove",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::String,
+                "the names of additional columns to remove",
             )
             .category(Category::Filters)
     }
 
     fn usage(&self) -> &str {
-        "Remove the last number of columns. If you want to remove columns by name, try 'reject'."
+        "Remove the last number of columns, or the named columns."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -39,32 +44,57 @@ impl Command for DropColumn {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        // the number of columns to drop
-        let columns: Option<i64> = call.opt(engine_state, stack, 0)?;
+        let columns: Option<Value> = call.opt(engine_state, stack, 0)?;
         let span = call.head;
 
-        let columns_to_drop = if let Some(quantity) = columns {
-            quantity
-        } else {
-            1
-        };
-
-        dropcol(engine_state, span, input, columns_to_drop)
+        match columns {
+            // the number of columns to drop, starting from the end
+            None => dropcol(engine_state, span, input, 1, None),
+            Some(Value::Int { val, .. }) => dropcol(engine_state, span, input, val, None),
+            // one or more column names to drop
+            Some(v) => {
+                let mut names = vec![v.as_string()?];
+                let rest: Vec<String> = call.rest(engine_state, stack, 1)?;
+                names.extend(rest);
+                dropcol(engine_state, span, input, 0, Some(names))
+            }
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Remove the last column of a table",
-            example: "echo [[lib, extension]; [nu-lib, rs] [nu-core, rb]] | drop column",
-            result: Some(Value::List {
-                vals: vec![Value::Record {
-                    cols: vec!["lib".into()],
-                    vals: vec![Value::test_string("nu-lib"), Value::test_string("nu-core")],
+        vec![
+            Example {
+                description: "Remove the last column of a table",
+                example: "echo [[lib, extension]; [nu-lib, rs] [nu-core, rb]] | drop column",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["lib".into()],
+                        vals: vec![Value::test_string("nu-lib"), Value::test_string("nu-core")],
+                        span: Span::test_data(),
+                    }],
                     span: Span::test_data(),
-                }],
-                span: Span::test_data(),
-            }),
-        }]
+                }),
+            },
+            Example {
+                description: "Remove a column by name",
+                example: "echo [[lib, extension]; [nu-lib, rs] [nu-core, rb]] | drop column lib",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["extension".into()],
+                            vals: vec![Value::test_string("rs")],
+                            span: Span::test_data(),
+                        },
+                        Value::Record {
+                            cols: vec!["extension".into()],
+                            vals: vec![Value::test_string("rb")],
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
     }
 }
 
@@ -72,7 +102,8 @@ fn dropcol(
     engine_state: &EngineState,
     span: Span,
     input: PipelineData,
-    columns: i64, // the number of columns to drop
+    columns: i64,               // the number of columns to drop
+    names: Option<Vec<String>>, // or the names of the columns to drop
 ) -> Result<PipelineData, ShellError> {
     let mut keep_columns = vec![];
 
@@ -86,7 +117,7 @@ fn dropcol(
         ) => {
             let mut output = vec![];
             let input_cols = get_input_cols(input_vals.clone());
-            let kc = get_keep_columns(input_cols, columns);
+            let kc = get_keep_columns(input_cols, columns, &names);
             keep_columns = get_cellpath_columns(kc, span);
 
             for input_val in input_vals {
@@ -110,7 +141,7 @@ fn dropcol(
 
             let v: Vec<_> = stream.into_iter().collect();
             let input_cols = get_input_cols(v.clone());
-            let kc = get_keep_columns(input_cols, columns);
+            let kc = get_keep_columns(input_cols, columns, &names);
             keep_columns = get_cellpath_columns(kc, span);
 
             for input_val in v {
@@ -170,7 +201,18 @@ fn get_cellpath_columns(keep_cols: Vec<String>, span: Span) -> Vec<CellPath> {
     output
 }
 
-fn get_keep_columns(input: Vec<String>, mut num_of_columns_to_drop: i64) -> Vec<String> {
+fn get_keep_columns(
+    input: Vec<String>,
+    mut num_of_columns_to_drop: i64,
+    names: &Option<Vec<String>>,
+) -> Vec<String> {
+    if let Some(names) = names {
+        return input
+            .into_iter()
+            .filter(|col| !names.contains(col))
+            .collect();
+    }
+
     let vlen: i64 = input.len() as i64;
 
     if num_of_columns_to_drop > vlen {
@@ -34,6 +34,10 @@ impl Command for Every {
         "Show (or skip) every n-th row, starting from the first one."
     }
 
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["downsample", "nth", "stride"]
+    }
+
     fn examples(&self) -> Vec<Example> {
         vec![
             Example {
@@ -1,3 +1,4 @@
+use nu_ansi_term::Style;
 use nu_engine::{eval_block, CallExt};
 use nu_protocol::{
     ast::Call,
@@ -45,6 +46,17 @@ impl Command for Find {
                 Some('s'),
             )
             .switch("invert", "invert the match", Some('v'))
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "column names to be searched (rest of input columns are ignored)",
+                Some('c'),
+            )
+            .switch(
+                "highlight",
+                "highlight the regex matches in string output",
+                None,
+            )
             .rest("rest", SyntaxShape::Any, "terms to search")
             .category(Category::Filters)
     }
@@ -116,6 +128,28 @@ impl Command for Find {
                     span: Span::test_data()
                 })
             },
+            Example {
+                description: "Find value in records, only searching in the given columns",
+                example: r#"[[version name]; [0.1.0 nushell] [0.1.1 fish] [0.2.0 zsh]] | find 0.1 --columns [version]"#,
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(
+                            vec!["version", "name"],
+                            vec![Value::test_string("0.1.0"), Value::test_string("nushell")]
+                        ),
+                        Value::test_record(
+                            vec!["version", "name"],
+                            vec![Value::test_string("0.1.1"), Value::test_string("fish")]
+                        ),
+                    ],
+                    span: Span::test_data()
+                }),
+            },
+            Example {
+                description: "Highlight the regex matches in the output",
+                example: r#"ls | get name | find --regex "toml" --highlight"#,
+                result: None,
+            },
             Example {
                 description: "Find value in records",
                 example: r#"[[version name]; [0.1.0 nushell] [0.1.1 fish] [0.2.0 zsh]] | find -r "nu""#,
@@ -169,6 +203,10 @@ fn find_with_regex(
     let multiline = call.has_flag("multiline");
     let dotall = call.has_flag("dotall");
     let invert = call.has_flag("invert");
+    let highlight = call.has_flag("highlight");
+    let columns: Vec<String> = call
+        .get_flag(engine_state, stack, "columns")?
+        .unwrap_or_default();
 
     let flags = match (insensitive, multiline, dotall) {
         (false, false, false) => "",
@@ -186,13 +224,17 @@ fn find_with_regex(
     let re = Regex::new(regex.as_str())
         .map_err(|e| ShellError::UnsupportedInput(format!("incorrect regex: {}", e), span))?;
 
-    input.filter(
+    let map_re = re.clone();
+
+    let filtered = input.filter(
         move |value| match value {
             Value::String { val, .. } => re.is_match(val.as_str()) != invert,
-            Value::Record { cols: _, vals, .. } => {
-                let matches: Vec<bool> = vals
+            Value::Record { cols, vals, .. } => {
+                let matches: Vec<bool> = cols
                     .iter()
-                    .map(|v| re.is_match(v.into_string(" ", &config).as_str()) != invert)
+                    .zip(vals.iter())
+                    .filter(|(col, _)| columns.is_empty() || columns.contains(*col))
+                    .map(|(_, v)| re.is_match(v.into_string(" ", &config).as_str()) != invert)
                     .collect();
                 matches.iter().any(|b| *b)
             }
@@ -205,8 +247,28 @@ fn find_with_regex(
             }
             _ => false,
         },
-        ctrlc,
-    )
+        ctrlc.clone(),
+    )?;
+
+    if highlight {
+        let style = Style::new().reverse();
+        filtered.map(
+            move |value| match value {
+                Value::String { val, span } => Value::String {
+                    val: map_re
+                        .replace_all(val.as_str(), |captures: &regex::Captures| {
+                            style.paint(&captures[0]).to_string()
+                        })
+                        .into_owned(),
+                    span,
+                },
+                value => value,
+            },
+            ctrlc,
+        )
+    } else {
+        Ok(filtered)
+    }
 }
 
 fn find_with_predicate(
@@ -273,6 +335,9 @@ fn find_with_rest(
     let engine_state = engine_state.clone();
     let config = stack.get_config()?;
     let invert = call.has_flag("invert");
+    let columns: Vec<String> = call
+        .get_flag(&engine_state, stack, "columns")?
+        .unwrap_or_default();
 
     let terms = call.rest::<Value>(&engine_state, stack, 0)?;
     let lower_terms = terms
@@ -313,20 +378,24 @@ fn find_with_rest(
                 | Value::CustomValue { .. } => term
                     .r#in(span, &lower_value, span)
                     .map_or(false, |value| value.is_true()),
-                Value::Record { vals, .. } => vals.iter().any(|val| {
-                    if let Ok(span) = val.span() {
-                        let lower_val = Value::string(
-                            val.into_string("", &config).to_lowercase(),
-                            Span::test_data(),
-                        );
+                Value::Record { cols, vals, .. } => cols
+                    .iter()
+                    .zip(vals.iter())
+                    .filter(|(col, _)| columns.is_empty() || columns.contains(*col))
+                    .any(|(_, val)| {
+                        if let Ok(span) = val.span() {
+                            let lower_val = Value::string(
+                                val.into_string("", &config).to_lowercase(),
+                                Span::test_data(),
+                            );
 
-                        term.r#in(span, &lower_val, span)
-                            .map_or(false, |value| value.is_true())
-                    } else {
-                        term.r#in(span, val, span)
-                            .map_or(false, |value| value.is_true())
-                    }
-                }),
+                            term.r#in(span, &lower_val, span)
+                                .map_or(false, |value| value.is_true())
+                        } else {
+                            term.r#in(span, val, span)
+                                .map_or(false, |value| value.is_true())
+                        }
+                    }),
                 Value::Binary { .. } => false,
             }) != invert
         },
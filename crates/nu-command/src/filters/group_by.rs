@@ -2,8 +2,8 @@ use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
 use nu_protocol::{
-    Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
-    Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Value,
 };
 
 use indexmap::IndexMap;
@@ -17,11 +17,9 @@ impl Command for GroupBy {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("group-by").optional(
-            "grouper",
-            SyntaxShape::Any,
-            "the grouper value to use",
-        )
+        Signature::build("group-by")
+            .optional("grouper", SyntaxShape::Any, "the grouper value to use")
+            .category(Category::Filters)
     }
 
     fn usage(&self) -> &str {
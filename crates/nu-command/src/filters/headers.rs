@@ -3,6 +3,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
 };
+use std::collections::HashSet;
 
 #[derive(Clone)]
 pub struct Headers;
@@ -13,7 +14,18 @@ impl Command for Headers {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build(self.name()).category(Category::Filters)
+        Signature::build(self.name())
+            .switch(
+                "trim",
+                "trim leading and trailing whitespace from header names",
+                Some('t'),
+            )
+            .switch(
+                "dedupe",
+                "make repeated header names unique by appending a numeric suffix",
+                Some('d'),
+            )
+            .category(Category::Filters)
     }
 
     fn usage(&self) -> &str {
@@ -66,6 +78,22 @@ impl Command for Headers {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Deduplicate repeated header names",
+                example: r#""a a b|1 2 3" | split row "|" | split column " " | headers -d"#,
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["a".to_string(), "a1".to_string(), "b".to_string()],
+                        vals: vec![
+                            Value::test_string("1"),
+                            Value::test_string("2"),
+                            Value::test_string("3"),
+                        ],
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -77,9 +105,11 @@ impl Command for Headers {
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let config = stack.get_config()?;
+        let trim = call.has_flag("trim");
+        let dedupe = call.has_flag("dedupe");
         let metadata = input.metadata();
         let value = input.into_value(call.head);
-        let headers = extract_headers(&value, &config)?;
+        let headers = extract_headers(&value, &config, trim, dedupe)?;
         let new_headers = replace_headers(value, &headers)?;
 
         Ok(new_headers.into_pipeline_data().set_metadata(metadata))
@@ -112,23 +142,44 @@ fn replace_headers(value: Value, headers: &[String]) -> Result<Value, ShellError
     }
 }
 
-fn extract_headers(value: &Value, config: &Config) -> Result<Vec<String>, ShellError> {
+fn extract_headers(
+    value: &Value,
+    config: &Config,
+    trim: bool,
+    dedupe: bool,
+) -> Result<Vec<String>, ShellError> {
     match value {
-        Value::Record { vals, .. } => Ok(vals
-            .iter()
-            .enumerate()
-            .map(|(idx, value)| {
-                let col = value.into_string("", config);
-                if col.is_empty() {
-                    format!("column{}", idx)
-                } else {
+        Value::Record { vals, .. } => {
+            let mut seen: HashSet<String> = HashSet::new();
+            Ok(vals
+                .iter()
+                .enumerate()
+                .map(|(idx, value)| {
+                    let mut col = value.into_string("", config);
+                    if trim {
+                        col = col.trim().to_string();
+                    }
+                    if col.is_empty() {
+                        col = format!("column{}", idx);
+                    }
+                    if dedupe {
+                        // Make repeated names unique by appending the first free numeric suffix
+                        if !seen.insert(col.clone()) {
+                            let mut suffix = 1;
+                            while seen.contains(&format!("{}{}", col, suffix)) {
+                                suffix += 1;
+                            }
+                            col = format!("{}{}", col, suffix);
+                            seen.insert(col.clone());
+                        }
+                    }
                     col
-                }
-            })
-            .collect::<Vec<String>>()),
+                })
+                .collect::<Vec<String>>())
+        }
         Value::List { vals, span } => vals
             .iter()
-            .map(|value| extract_headers(value, config))
+            .map(|value| extract_headers(value, config, trim, dedupe))
             .next()
             .ok_or_else(|| {
                 ShellError::SpannedLabeledError(
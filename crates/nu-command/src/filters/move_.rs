@@ -195,32 +195,40 @@ fn move_record_columns(
 ) -> Result<Value, ShellError> {
     let mut column_idx: Vec<usize> = Vec::with_capacity(columns.len());
 
-    // Check if before/after column exist
-    match &before_or_after.item {
-        BeforeOrAfter::After(after) => {
-            if !inp_cols.contains(after) {
-                return Err(ShellError::SpannedLabeledError(
-                    "Cannot move columns".to_string(),
-                    "column does not exist".to_string(),
-                    before_or_after.span,
-                ));
-            }
-        }
-        BeforeOrAfter::Before(before) => {
-            if !inp_cols.contains(before) {
-                return Err(ShellError::SpannedLabeledError(
-                    "Cannot move columns".to_string(),
-                    "column does not exist".to_string(),
-                    before_or_after.span,
-                ));
-            }
-        }
+    if columns.is_empty() {
+        return Err(ShellError::SpannedLabeledError(
+            "Cannot move columns".to_string(),
+            "no columns given to move".to_string(),
+            span,
+        ));
+    }
+
+    // Check if before/after column exists
+    let pivot = match &before_or_after.item {
+        BeforeOrAfter::After(after) => after,
+        BeforeOrAfter::Before(before) => before,
+    };
+    if !inp_cols.contains(pivot) {
+        return Err(ShellError::SpannedLabeledError(
+            "Cannot move columns".to_string(),
+            "column does not exist".to_string(),
+            before_or_after.span,
+        ));
     }
 
     // Find indices of columns to be moved
     for column in columns.iter() {
         let column_str = column.as_string()?;
 
+        // Moving a column relative to itself would duplicate it
+        if &column_str == pivot {
+            return Err(ShellError::SpannedLabeledError(
+                "Cannot move columns".to_string(),
+                "cannot move a column relative to itself".to_string(),
+                column.span()?,
+            ));
+        }
+
         if let Some(idx) = inp_cols.iter().position(|inp_col| &column_str == inp_col) {
             column_idx.push(idx);
         } else {
@@ -232,8 +240,6 @@ fn move_record_columns(
         }
     }
 
-    if columns.is_empty() {}
-
     let mut out_cols: Vec<String> = Vec::with_capacity(inp_cols.len());
     let mut out_vals: Vec<Value> = Vec::with_capacity(inp_vals.len());
 
@@ -71,6 +71,35 @@ impl Command for Range {
         let rows_from = get_range_val(rows.from);
         let rows_to = get_range_val(rows.to);
 
+        // Slicing binary returns binary, eg `0x[de ad be ef] | range 1..2`
+        let input = match input {
+            PipelineData::Value(Value::Binary { val, span }, metadata) => {
+                let len = val.len() as i64;
+
+                let from = if rows_from < 0 {
+                    len + rows_from
+                } else {
+                    rows_from
+                }
+                .max(0) as usize;
+
+                let to = if rows_to < 0 {
+                    len + rows_to
+                } else {
+                    rows_to.min(len - 1)
+                };
+
+                let val = if len == 0 || (from as i64) > to {
+                    vec![]
+                } else {
+                    val[from..=(to as usize)].to_vec()
+                };
+
+                return Ok(PipelineData::Value(Value::Binary { val, span }, metadata));
+            }
+            input => input,
+        };
+
         // only collect the input if we have any negative indices
         if rows_from < 0 || rows_to < 0 {
             let v: Vec<_> = input.into_iter().collect();
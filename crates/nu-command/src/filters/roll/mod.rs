@@ -23,6 +23,11 @@ fn vertical_rotate_value(
 ) -> Result<Value, ShellError> {
     match value {
         Value::List { mut vals, span } => {
+            // Rotating an empty list is a no-op; avoid the modulo by zero below
+            if vals.is_empty() {
+                return Ok(Value::List { vals, span });
+            }
+
             let rotations = by.map(|n| n % vals.len()).unwrap_or(1);
             let values = vals.as_mut_slice();
 
@@ -57,6 +62,11 @@ fn horizontal_rotate_value(
             mut vals,
             span,
         } => {
+            // Rotating an empty record is a no-op; avoid the modulo by zero below
+            if vals.is_empty() {
+                return Ok(Value::Record { cols, vals, span });
+            }
+
             let rotations = by.map(|n| n % vals.len()).unwrap_or(1);
 
             let columns = if cells_only {
@@ -2,7 +2,7 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -14,11 +14,9 @@ impl Command for SplitBy {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("split-by").optional(
-            "splitter",
-            SyntaxShape::Any,
-            "the splitter value to use",
-        )
+        Signature::build("split-by")
+            .optional("splitter", SyntaxShape::Any, "the splitter value to use")
+            .category(Category::Filters)
     }
 
     fn usage(&self) -> &str {
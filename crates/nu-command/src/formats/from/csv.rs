@@ -99,7 +99,14 @@ fn from_csv(
         _ => ',',
     };
 
-    from_delimited_data(noheaders, sep, input, name, &config)
+    from_delimited_data(
+        noheaders,
+        sep,
+        input,
+        name,
+        &config,
+        engine_state.ctrlc.clone(),
+    )
 }
 
 #[cfg(test)]
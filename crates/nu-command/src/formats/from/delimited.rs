@@ -1,8 +1,12 @@
 use csv::ReaderBuilder;
-use nu_protocol::{Config, IntoPipelineData, PipelineData, ShellError, Span, Value};
+use nu_protocol::{
+    Config, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError, Span, Value,
+};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 fn from_delimited_string_to_value(
-    s: String,
+    s: &str,
     noheaders: bool,
     separator: char,
     span: Span,
@@ -51,12 +55,42 @@ pub fn from_delimited_data(
     input: PipelineData,
     name: Span,
     config: &Config,
+    ctrlc: Option<Arc<AtomicBool>>,
 ) -> Result<PipelineData, ShellError> {
     let concat_string = input.collect_string("", config)?;
 
-    Ok(
-        from_delimited_string_to_value(concat_string, noheaders, sep, name)
-            .map_err(|x| ShellError::DelimiterError(x.to_string(), name))?
-            .into_pipeline_data(),
-    )
+    let value = from_delimited_string_to_value(&concat_string, noheaders, sep, name)
+        .map_err(|x| from_csv_error(x, &concat_string, name))?;
+
+    // Stream the rows so a pipeline over a huge file stays interruptible
+    match value {
+        Value::List { vals, .. } => Ok(vals.into_iter().into_pipeline_data(ctrlc)),
+        other => Ok(other.into_pipeline_data()),
+    }
+}
+
+/// Point at the offending line and byte of the input, the way `from json` does
+/// for syntax errors
+fn from_csv_error(err: csv::Error, input: &str, span: Span) -> ShellError {
+    match err.position() {
+        Some(pos) => {
+            let byte = pos.byte() as usize;
+
+            ShellError::SpannedLabeledErrorRelated(
+                "Error while parsing delimited text".into(),
+                "error parsing delimited text".into(),
+                span,
+                vec![ShellError::OutsideSpannedLabeledError(
+                    input.to_string(),
+                    "Error while parsing delimited text".into(),
+                    format!("{} (line {}, byte {})", err, pos.line(), byte),
+                    Span {
+                        start: byte,
+                        end: byte,
+                    },
+                )],
+            )
+        }
+        None => ShellError::DelimiterError(err.to_string(), span),
+    }
 }
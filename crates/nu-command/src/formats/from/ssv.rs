@@ -193,7 +193,16 @@ fn parse_separated_columns<'a>(
     };
 
     let parse_without_headers = |ls: Vec<&str>| {
-        let num_columns = ls.iter().map(|r| r.len()).max().unwrap_or(0);
+        let num_columns = ls
+            .iter()
+            .map(|r| {
+                r.split(separator)
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .count()
+            })
+            .max()
+            .unwrap_or(0);
 
         let headers = (1..=num_columns)
             .map(|i| format!("column{}", i))
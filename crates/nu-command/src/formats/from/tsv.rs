@@ -28,13 +28,13 @@ impl Command for FromTsv {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
+        engine_state: &EngineState,
         stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let config = stack.get_config().unwrap_or_default();
-        from_tsv(call, input, &config)
+        from_tsv(engine_state, call, input, &config)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -53,12 +53,24 @@ impl Command for FromTsv {
     }
 }
 
-fn from_tsv(call: &Call, input: PipelineData, config: &Config) -> Result<PipelineData, ShellError> {
+fn from_tsv(
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+    config: &Config,
+) -> Result<PipelineData, ShellError> {
     let name = call.head;
 
     let noheaders = call.has_flag("noheaders");
 
-    from_delimited_data(noheaders, '\t', input, name, config)
+    from_delimited_data(
+        noheaders,
+        '\t',
+        input,
+        name,
+        config,
+        engine_state.ctrlc.clone(),
+    )
 }
 
 #[cfg(test)]
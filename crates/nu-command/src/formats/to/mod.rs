@@ -5,6 +5,7 @@ mod html;
 mod json;
 mod md;
 mod nuon;
+mod text;
 mod toml;
 mod tsv;
 mod url;
@@ -19,6 +20,7 @@ pub use html::ToHtml;
 pub use json::ToJson;
 pub use md::ToMd;
 pub use nuon::ToNuon;
+pub use text::ToText;
 pub use tsv::ToTsv;
 pub use xml::ToXml;
 pub use yaml::ToYaml;
@@ -0,0 +1,137 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, Example, IntoPipelineData, ListStream, PipelineData, RawStream, ShellError,
+    Signature, Value,
+};
+
+#[cfg(windows)]
+const LINE_ENDING: &str = "\r\n";
+#[cfg(not(windows))]
+const LINE_ENDING: &str = "\n";
+
+#[derive(Clone)]
+pub struct ToText;
+
+impl Command for ToText {
+    fn name(&self) -> &str {
+        "to text"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to text").category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Converts data into simple text."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let config = stack.get_config().unwrap_or_default();
+
+        if let PipelineData::ListStream(stream, _) = input {
+            // Convert the stream value by value so a huge pipeline doesn't get
+            // collected into one giant string before anything is written out.
+            Ok(PipelineData::ExternalStream {
+                stdout: Some(RawStream::new(
+                    Box::new(ListStreamIterator {
+                        stream,
+                        separator: LINE_ENDING.to_string(),
+                        config,
+                    }),
+                    engine_state.ctrlc.clone(),
+                    head,
+                )),
+                stderr: None,
+                exit_code: None,
+                span: head,
+                metadata: None,
+            })
+        } else {
+            Ok(Value::String {
+                val: local_into_string(input.into_value(head), LINE_ENDING, &config),
+                span: head,
+            }
+            .into_pipeline_data())
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Outputs data as simple text",
+                example: "1 | to text",
+                result: Some(Value::test_string("1")),
+            },
+            Example {
+                description: "Outputs external data as simple text",
+                example: "git help -a | lines | find -r '^ ' | to text",
+                result: None,
+            },
+            Example {
+                description: "Outputs records as simple text",
+                example: "ls | to text",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct ListStreamIterator {
+    stream: ListStream,
+    separator: String,
+    config: Config,
+}
+
+impl Iterator for ListStreamIterator {
+    type Item = Result<Vec<u8>, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.stream.next() {
+            let mut string = local_into_string(item, &self.separator, &self.config);
+            string.push_str(&self.separator);
+            Some(Ok(string.into_bytes()))
+        } else {
+            None
+        }
+    }
+}
+
+fn local_into_string(value: Value, separator: &str, config: &Config) -> String {
+    match value {
+        Value::List { vals, .. } => vals
+            .into_iter()
+            .map(|v| local_into_string(v, separator, config))
+            .collect::<Vec<String>>()
+            .join(separator),
+        Value::Record { cols, vals, .. } => cols
+            .iter()
+            .zip(vals)
+            .map(|(name, value)| {
+                format!("{}: {}", name, local_into_string(value, separator, config))
+            })
+            .collect::<Vec<String>>()
+            .join(separator),
+        Value::Error { error } => error.to_string(),
+        value => value.into_string(", ", config),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToText {})
+    }
+}
@@ -1,7 +1,9 @@
 mod cal;
 mod seq;
+mod seq_char;
 mod seq_date;
 
 pub use cal::Cal;
 pub use seq::Seq;
+pub use seq_char::SeqChar;
 pub use seq_date::SeqDate;
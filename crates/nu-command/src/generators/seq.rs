@@ -2,10 +2,12 @@ use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
-    SyntaxShape, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Value,
 };
 use std::cmp;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Seq;
@@ -179,7 +181,14 @@ fn seq(
 
     let rest_nums: Vec<String> = rest_nums.iter().map(|n| n.item.to_string()).collect();
 
-    run_seq(sep, Some(term), widths, rest_nums, span)
+    run_seq(
+        sep,
+        Some(term),
+        widths,
+        rest_nums,
+        span,
+        engine_state.ctrlc.clone(),
+    )
 }
 
 #[cfg(test)]
@@ -217,6 +226,7 @@ pub fn run_seq(
     widths: bool,
     free: Vec<String>,
     span: Span,
+    ctrlc: Option<Arc<AtomicBool>>,
 ) -> Result<PipelineData, ShellError> {
     let mut largest_dec = 0;
     let mut padding = 0;
@@ -274,6 +284,7 @@ pub fn run_seq(
         widths,
         padding,
         span,
+        ctrlc,
     ))
 }
 
@@ -296,32 +307,54 @@ fn print_seq(
     pad: bool,
     padding: usize,
     span: Span,
+    ctrlc: Option<Arc<AtomicBool>>,
 ) -> PipelineData {
+    // If the separator and terminator are line endings we can convert to numbers
+    let use_num =
+        (separator == "\n" || separator == "\r") && (terminator == "\n" || terminator == "\r");
+
+    if use_num {
+        // we'd like to keep the datatype the same for the output, so check
+        // and see if any of the output will really be decimals, and if it is
+        // we'll make the entire output decimals
+        let contains_decimals = first.fract() != 0.0 || step.fract() != 0.0 || last.fract() != 0.0;
+
+        // Stream the values lazily so `seq` over a huge span stays interruptible
+        return (0i64..)
+            .map(move |i| first + i as f64 * step)
+            .take_while(move |value| !done_printing(*value, step, last))
+            .map(move |value| {
+                if contains_decimals {
+                    Value::float(value, span)
+                } else {
+                    Value::int(value as i64, span)
+                }
+            })
+            .into_pipeline_data(ctrlc);
+    }
+
     let mut i = 0isize;
     let mut value = first + i as f64 * step;
     // for string output
     let mut ret_str = "".to_owned();
-    // for number output
-    let mut ret_num = vec![];
-    // If the separator and terminator are line endings we can convert to numbers
-    let use_num =
-        (separator == "\n" || separator == "\r") && (terminator == "\n" || terminator == "\r");
 
     while !done_printing(value, step, last) {
-        if use_num {
-            ret_num.push(value);
-        } else {
-            // formatting for string output with potential padding
-            let istr = format!("{:.*}", largest_dec, value);
-            let ilen = istr.len();
-            let before_dec = istr.find('.').unwrap_or(ilen);
-            if pad && before_dec < padding {
-                for _ in 0..(padding - before_dec) {
-                    ret_str.push('0');
-                }
+        if let Some(ctrlc) = &ctrlc {
+            if ctrlc.load(Ordering::SeqCst) {
+                break;
             }
-            ret_str.push_str(&istr);
         }
+
+        // formatting for string output with potential padding
+        let istr = format!("{:.*}", largest_dec, value);
+        let ilen = istr.len();
+        let before_dec = istr.find('.').unwrap_or(ilen);
+        if pad && before_dec < padding {
+            for _ in 0..(padding - before_dec) {
+                ret_str.push('0');
+            }
+        }
+        ret_str.push_str(&istr);
         i += 1;
         value = first + i as f64 * step;
         if !done_printing(value, step, last) {
@@ -329,41 +362,10 @@ fn print_seq(
         }
     }
 
-    if !use_num && ((first >= last && step < 0f64) || (first <= last && step > 0f64)) {
+    if (first >= last && step < 0f64) || (first <= last && step > 0f64) {
         ret_str.push_str(&terminator);
     }
 
-    if use_num {
-        // we'd like to keep the datatype the same for the output, so check
-        // and see if any of the output is really decimals, and if it is
-        // we'll make the entire output decimals
-        let contains_decimals = vec_contains_decimals(&ret_num);
-        let rows: Vec<Value> = ret_num
-            .iter()
-            .map(|v| {
-                if contains_decimals {
-                    Value::float(*v, span)
-                } else {
-                    Value::int(*v as i64, span)
-                }
-            })
-            .collect();
-
-        Value::List { vals: rows, span }.into_pipeline_data()
-    } else {
-        let rows: String = ret_str.lines().collect();
-        Value::string(rows, span).into_pipeline_data()
-    }
-}
-
-fn vec_contains_decimals(array: &[f64]) -> bool {
-    let mut found_decimal = false;
-    for x in array {
-        if x.fract() != 0.0 {
-            found_decimal = true;
-            break;
-        }
-    }
-
-    found_decimal
+    let rows: String = ret_str.lines().collect();
+    Value::string(rows, span).into_pipeline_data()
 }
@@ -0,0 +1,171 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct SeqChar;
+
+impl Command for SeqChar {
+    fn name(&self) -> &str {
+        "seq char"
+    }
+
+    fn usage(&self) -> &str {
+        "Print sequences of characters"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("seq char")
+            .required("start", SyntaxShape::String, "start of character sequence")
+            .required("end", SyntaxShape::String, "end of character sequence")
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "separator character (defaults to \\n)",
+                Some('s'),
+            )
+            .category(Category::Generators)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "sequence a to e with newline separator",
+                example: "seq char a e",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("a"),
+                        Value::test_string("b"),
+                        Value::test_string("c"),
+                        Value::test_string("d"),
+                        Value::test_string("e"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "sequence a to e with pipe separator",
+                example: "seq char -s '|' a e",
+                result: Some(Value::test_string("a|b|c|d|e")),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        seq_char(engine_state, stack, call)
+    }
+}
+
+fn is_single_character(ch: &str) -> bool {
+    ch.is_ascii() && ch.len() == 1
+}
+
+fn seq_char(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let start: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let end: Spanned<String> = call.req(engine_state, stack, 1)?;
+    let separator: Option<Spanned<String>> = call.get_flag(engine_state, stack, "separator")?;
+
+    if !is_single_character(&start.item) {
+        return Err(ShellError::SpannedLabeledError(
+            "seq char only accepts individual ASCII characters as parameters".into(),
+            "should be 1 character long".into(),
+            start.span,
+        ));
+    }
+
+    if !is_single_character(&end.item) {
+        return Err(ShellError::SpannedLabeledError(
+            "seq char only accepts individual ASCII characters as parameters".into(),
+            "should be 1 character long".into(),
+            end.span,
+        ));
+    }
+
+    let start = start
+        .item
+        .chars()
+        .next()
+        .expect("seq char input must contain 2 inputs");
+
+    let end = end
+        .item
+        .chars()
+        .next()
+        .expect("seq char input must contain 2 inputs");
+
+    let sep: String = match separator {
+        Some(s) => {
+            if s.item == r"\t" {
+                '\t'.to_string()
+            } else if s.item == r"\n" {
+                '\n'.to_string()
+            } else if s.item == r"\r" {
+                '\r'.to_string()
+            } else {
+                let vec_s: Vec<char> = s.item.chars().collect();
+                if vec_s.is_empty() {
+                    return Err(ShellError::SpannedLabeledError(
+                        "Expected a single separator char from --separator".into(),
+                        "requires a single character string input".into(),
+                        s.span,
+                    ));
+                };
+                vec_s.iter().collect()
+            }
+        }
+        _ => '\n'.to_string(),
+    };
+
+    run_seq_char(start, end, sep, call.head)
+}
+
+fn run_seq_char(
+    start_ch: char,
+    end_ch: char,
+    sep: String,
+    span: Span,
+) -> Result<PipelineData, ShellError> {
+    let mut result_vec = vec![];
+    for current_ch in start_ch as u8..end_ch as u8 + 1 {
+        result_vec.push((current_ch as char).to_string())
+    }
+
+    if sep == "\n" || sep == "\r" {
+        let result = result_vec
+            .into_iter()
+            .map(|x| Value::String { val: x, span })
+            .collect::<Vec<Value>>();
+
+        Ok(Value::List { vals: result, span }.into_pipeline_data())
+    } else {
+        let result = result_vec.join(&sep);
+
+        Ok(Value::String { val: result, span }.into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SeqChar {})
+    }
+}
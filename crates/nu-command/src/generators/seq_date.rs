@@ -1,5 +1,5 @@
 use chrono::naive::NaiveDate;
-use chrono::{Duration, Local};
+use chrono::{Datelike, Duration, Local, Weekday};
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -7,6 +7,8 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
     SyntaxShape, Value,
 };
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SeqDate;
@@ -60,6 +62,17 @@ impl Command for SeqDate {
                 Some('d'),
             )
             .switch("reverse", "print dates in reverse", Some('r'))
+            .switch(
+                "weekdays",
+                "exclude weekend days from the sequence",
+                Some('w'),
+            )
+            .named(
+                "weekend",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "days to treat as the weekend (defaults to [saturday sunday])",
+                None,
+            )
             .category(Category::Generators)
     }
 
@@ -117,6 +130,23 @@ impl Command for SeqDate {
                     span,
                 }),
             },
+            Example {
+                description: "print the weekdays between January 1st 2020 and January 10th 2020",
+                example: "seq date -b '2020-01-01' -e '2020-01-10' --weekdays",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::String { val: "2020-01-01".into(), span, },
+                        Value::String { val: "2020-01-02".into(), span, },
+                        Value::String { val: "2020-01-03".into(), span, },
+                        Value::String { val: "2020-01-06".into(), span, },
+                        Value::String { val: "2020-01-07".into(), span, },
+                        Value::String { val: "2020-01-08".into(), span, },
+                        Value::String { val: "2020-01-09".into(), span, },
+                        Value::String { val: "2020-01-10".into(), span, },
+                    ],
+                    span,
+                }),
+            },
             Example {
                 description: "starting on May 5th, 2020, print the next 10 days in your locale's date format, colon separated",
                 example: "seq date -o %x -s ':' -d 10 -b '2020-05-01'",
@@ -143,6 +173,33 @@ impl Command for SeqDate {
         let increment: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "increment")?;
         let days: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "days")?;
         let reverse = call.has_flag("reverse");
+        let weekdays = call.has_flag("weekdays");
+        let weekend: Option<Value> = call.get_flag(engine_state, stack, "weekend")?;
+
+        // A custom weekend implies skipping it
+        let skip_weekend = if weekdays || weekend.is_some() {
+            Some(match weekend {
+                Some(val) => {
+                    let mut days = vec![];
+                    for name in val.as_list()? {
+                        match name.as_string()?.to_lowercase().parse::<Weekday>() {
+                            Ok(day) => days.push(day),
+                            Err(_) => {
+                                return Err(ShellError::SpannedLabeledError(
+                                    format!("'{}' is not a day of the week", name.as_string()?),
+                                    "expected a day name like 'saturday' or 'sat'".to_string(),
+                                    name.span()?,
+                                ))
+                            }
+                        }
+                    }
+                    days
+                }
+                None => vec![Weekday::Sat, Weekday::Sun],
+            })
+        } else {
+            None
+        };
 
         let sep: String = match separator {
             Some(s) => {
@@ -199,10 +256,19 @@ impl Command for SeqDate {
             rev = reverse;
         }
 
-        Ok(
-            run_seq_dates(sep, outformat, informat, begin, end, inc, day_count, rev)?
-                .into_pipeline_data(),
-        )
+        Ok(run_seq_dates(
+            sep,
+            outformat,
+            informat,
+            begin,
+            end,
+            inc,
+            day_count,
+            rev,
+            skip_weekend,
+            engine_state.ctrlc.clone(),
+        )?
+        .into_pipeline_data())
     }
 }
 
@@ -224,6 +290,8 @@ pub fn run_seq_dates(
     increment: Value,
     day_count: Option<Value>,
     reverse: bool,
+    skip_weekend: Option<Vec<Weekday>>,
+    ctrlc: Option<Arc<AtomicBool>>,
 ) -> Result<Value, ShellError> {
     let today = Local::today().naive_local();
     let mut step_size: i64 = increment
@@ -336,14 +404,29 @@ pub fn run_seq_dates(
 
     let mut ret_str = String::from("");
     loop {
-        ret_str.push_str(&next.format(&out_format).to_string());
+        if let Some(ctrlc) = &ctrlc {
+            if ctrlc.load(Ordering::SeqCst) {
+                break;
+            }
+        }
+
+        let skipped = match &skip_weekend {
+            Some(weekend) => weekend.contains(&next.weekday()),
+            None => false,
+        };
+
+        if !skipped {
+            if !ret_str.is_empty() {
+                ret_str.push_str(&separator);
+            }
+            ret_str.push_str(&next.format(&out_format).to_string());
+        }
+
         next += Duration::days(step_size);
 
         if is_out_of_range(next) {
             break;
         }
-
-        ret_str.push_str(&separator);
     }
 
     let rows: Vec<Value> = ret_str
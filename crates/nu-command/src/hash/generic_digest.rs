@@ -36,11 +36,17 @@ where
     }
 
     fn signature(&self) -> Signature {
-        Signature::build(self.name()).rest(
-            "rest",
-            SyntaxShape::CellPath,
-            format!("optionally {} hash data by cell path", D::name()),
-        )
+        Signature::build(self.name())
+            .switch(
+                "files",
+                "hash the contents of the file at the given path instead of the path itself",
+                Some('f'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                format!("optionally {} hash data by cell path", D::name()),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -59,16 +65,29 @@ where
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let hash_files = call.has_flag("files");
 
         input.map(
             move |v| {
                 if cell_paths.is_empty() {
-                    action::<D>(&v)
+                    if hash_files {
+                        action_on_file::<D>(&v)
+                    } else {
+                        action::<D>(&v)
+                    }
                 } else {
                     let mut v = v;
                     for path in &cell_paths {
-                        let ret = v
-                            .update_cell_path(&path.members, Box::new(move |old| action::<D>(old)));
+                        let ret = v.update_cell_path(
+                            &path.members,
+                            Box::new(move |old| {
+                                if hash_files {
+                                    action_on_file::<D>(old)
+                                } else {
+                                    action::<D>(old)
+                                }
+                            }),
+                        );
                         if let Err(error) = ret {
                             return Value::Error { error };
                         }
@@ -111,3 +130,46 @@ where
     let val = format!("{:x}", D::digest(bytes));
     Value::String { val, span }
 }
+
+/// Treat the value as a file path and hash the contents of that file, for
+/// building integrity manifests out of tables of file names
+pub fn action_on_file<D>(input: &Value) -> Value
+where
+    D: HashDigest,
+    digest::Output<D>: core::fmt::LowerHex,
+{
+    let (path, span) = match input {
+        Value::String { val, span } => (val, *span),
+        other => {
+            let span = match input.span() {
+                Ok(span) => span,
+                Err(error) => return Value::Error { error },
+            };
+
+            return Value::Error {
+                error: ShellError::UnsupportedInput(
+                    format!(
+                        "Type `{}` is not supported as a file path for {} hashing",
+                        other.get_type(),
+                        D::name()
+                    ),
+                    span,
+                ),
+            };
+        }
+    };
+
+    match std::fs::read(path) {
+        Ok(bytes) => Value::String {
+            val: format!("{:x}", D::digest(&bytes)),
+            span,
+        },
+        Err(err) => Value::Error {
+            error: ShellError::SpannedLabeledError(
+                format!("Could not read file '{}'", path),
+                err.to_string(),
+                span,
+            ),
+        },
+    }
+}
@@ -1,3 +1,5 @@
+mod bytes;
+mod charting;
 mod conversions;
 mod core_commands;
 mod date;
@@ -21,6 +23,8 @@ mod strings;
 mod system;
 mod viewers;
 
+pub use bytes::*;
+pub use charting::*;
 pub use conversions::*;
 pub use core_commands::*;
 pub use date::*;
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 use nu_engine::CallExt;
 use nu_protocol::{
@@ -10,6 +10,7 @@ use super::PathSubcommandArguments;
 struct Arguments {
     path: Spanned<String>,
     columns: Option<Vec<String>>,
+    allow_parent: bool,
 }
 
 impl PathSubcommandArguments for Arguments {
@@ -39,6 +40,11 @@ impl Command for SubCommand {
                 "Optionally operate by column path",
                 Some('c'),
             )
+            .switch(
+                "allow-parent",
+                "Emit ../ segments when the input path is not under the argument path",
+                Some('p'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -48,7 +54,8 @@ impl Command for SubCommand {
     fn extra_usage(&self) -> &str {
         r#"Can be used only when the input and the argument paths are either both
 absolute or both relative. The argument path needs to be a parent of the input
-path."#
+path, unless --allow-parent is given, in which case ../ segments are emitted to
+reach the input path."#
     }
 
     fn run(
@@ -62,6 +69,7 @@ path."#
         let args = Arguments {
             path: call.req(engine_state, stack, 0)?,
             columns: call.get_flag(engine_state, stack, "columns")?,
+            allow_parent: call.has_flag("allow-parent"),
         };
 
         input.map(
@@ -88,6 +96,11 @@ path."#
                 example: r"'eggs\bacon\sausage\spam' | path relative-to 'eggs\bacon\sausage'",
                 result: Some(Value::test_string(r"spam")),
             },
+            Example {
+                description: "Find a relative path to a sibling directory",
+                example: r"'C:\Users\viking' | path relative-to 'C:\Users\rust' --allow-parent",
+                result: Some(Value::test_string(r"..\viking")),
+            },
         ]
     }
 
@@ -109,19 +122,82 @@ path."#
                 example: r"'eggs/bacon/sausage/spam' | path relative-to 'eggs/bacon/sausage'",
                 result: Some(Value::test_string(r"spam")),
             },
+            Example {
+                description: "Find a relative path to a sibling directory",
+                example: r"'/home/viking' | path relative-to '/home/rust' --allow-parent",
+                result: Some(Value::test_string(r"../viking")),
+            },
         ]
     }
 }
 
 fn relative_to(path: &Path, span: Span, args: &Arguments) -> Value {
-    match path.strip_prefix(Path::new(&args.path.item)) {
+    let base = Path::new(&args.path.item);
+
+    match path.strip_prefix(base) {
         Ok(p) => Value::string(p.to_string_lossy(), span),
-        Err(e) => Value::Error {
-            error: ShellError::CantConvert(e.to_string(), "string".into(), span),
-        },
+        Err(_) => {
+            if args.allow_parent {
+                if let Some(p) = diff_with_parents(path, base) {
+                    return Value::string(p.to_string_lossy(), span);
+                }
+            }
+
+            Value::Error {
+                error: ShellError::SpannedLabeledError(
+                    format!(
+                        "'{}' is not under '{}'",
+                        path.to_string_lossy(),
+                        args.path.item
+                    ),
+                    "not a parent of the input path".into(),
+                    args.path.span,
+                ),
+            }
+        }
     }
 }
 
+/// Compute a relative path between two paths, emitting ../ segments as needed
+///
+/// Same approach as the pathdiff crate; returns None when the paths cannot be
+/// related (e.g. one is absolute and the other is not).
+fn diff_with_parents(path: &Path, base: &Path) -> Option<PathBuf> {
+    if path.is_absolute() != base.is_absolute() {
+        return None;
+    }
+
+    let mut ita = path.components();
+    let mut itb = base.components();
+    let mut comps: Vec<Component> = vec![];
+
+    loop {
+        match (ita.next(), itb.next()) {
+            (None, None) => break,
+            (Some(a), None) => {
+                comps.push(a);
+                comps.extend(ita.by_ref());
+                break;
+            }
+            (None, _) => comps.push(Component::ParentDir),
+            (Some(a), Some(b)) if comps.is_empty() && a == b => (),
+            (Some(a), Some(b)) if b == Component::CurDir => comps.push(a),
+            (_, Some(b)) if b == Component::ParentDir => return None,
+            (Some(a), Some(_)) => {
+                comps.push(Component::ParentDir);
+                for _ in itb.by_ref() {
+                    comps.push(Component::ParentDir);
+                }
+                comps.push(a);
+                comps.extend(ita.by_ref());
+                break;
+            }
+        }
+    }
+
+    Some(comps.iter().map(|c| c.as_os_str()).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -38,11 +38,18 @@ impl Command for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Strip ANSI escape sequences from a string",
-            example: r#"echo [ (ansi green) (ansi cursor_on) "hello" ] | str collect | ansi strip"#,
-            result: Some(Value::test_string("hello")),
-        }]
+        vec![
+            Example {
+                description: "Strip ANSI escape sequences from a string",
+                example: r#"echo [ (ansi green) (ansi cursor_on) "hello" ] | str collect | ansi strip"#,
+                result: Some(Value::test_string("hello")),
+            },
+            Example {
+                description: "Strip ANSI escape sequences from every string in a table",
+                example: r#"[[name]; [$"(ansi green)hello(ansi reset)"]] | ansi strip"#,
+                result: None,
+            },
+        ]
     }
 }
 
@@ -89,13 +96,17 @@ fn action(input: &Value, command_span: &Span) -> Value {
 
             Value::string(stripped_string, *span)
         }
-        other => {
-            let got = format!("value is {}, not string", other.get_type());
-
-            Value::Error {
-                error: ShellError::TypeMismatch(got, other.span().unwrap_or(*command_span)),
-            }
-        }
+        Value::Record { cols, vals, span } => Value::Record {
+            cols: cols.clone(),
+            vals: vals.iter().map(|v| action(v, command_span)).collect(),
+            span: *span,
+        },
+        Value::List { vals, span } => Value::List {
+            vals: vals.iter().map(|v| action(v, command_span)).collect(),
+            span: *span,
+        },
+        // leave non-string cells alone so tables with mixed columns pass through
+        other => other.clone(),
     }
 }
 
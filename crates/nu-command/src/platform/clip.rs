@@ -0,0 +1,79 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature};
+
+#[derive(Clone)]
+pub struct Clip;
+
+impl Command for Clip {
+    fn name(&self) -> &str {
+        "clip"
+    }
+
+    fn usage(&self) -> &str {
+        "Copy the pipeline output to the system clipboard."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("clip")
+            .switch(
+                "raw",
+                "copy the raw string instead of the rendered output",
+                Some('r'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let config = stack.get_config().unwrap_or_default();
+
+        let output = if call.has_flag("raw") {
+            input.collect_string("\n", &config)?
+        } else {
+            // Render through `table` so what is pasted matches what would
+            // have been printed
+            let table = crate::Table.run(engine_state, stack, &Call::new(head), input)?;
+            table.collect_string("\n", &config)?
+        };
+
+        let mut clipboard = arboard::Clipboard::new().map_err(|err| {
+            ShellError::SpannedLabeledError(
+                "Could not access the system clipboard".into(),
+                err.to_string(),
+                head,
+            )
+        })?;
+
+        clipboard.set_text(output).map_err(|err| {
+            ShellError::SpannedLabeledError(
+                "Could not write to the system clipboard".into(),
+                err.to_string(),
+                head,
+            )
+        })?;
+
+        Ok(PipelineData::new(head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Copy a rendered table to the clipboard",
+                example: "ls | clip",
+                result: None,
+            },
+            Example {
+                description: "Copy a string as-is to the clipboard",
+                example: "'secret' | clip --raw",
+                result: None,
+            },
+        ]
+    }
+}
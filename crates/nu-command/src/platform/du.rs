@@ -71,7 +71,7 @@ impl Command for Du {
                 "Exclude files below this size",
                 Some('m'),
             )
-            .category(Category::Core)
+            .category(Category::Platform)
     }
 
     fn run(
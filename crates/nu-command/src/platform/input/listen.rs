@@ -0,0 +1,212 @@
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent,
+};
+use crossterm::{execute, terminal};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value,
+};
+use std::io::stdout;
+
+#[derive(Clone)]
+pub struct InputListen;
+
+impl Command for InputListen {
+    fn name(&self) -> &str {
+        "input listen"
+    }
+
+    fn usage(&self) -> &str {
+        "Listen for user interface events and return the first one as a record."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["prompt", "interactive", "keycode"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("input listen")
+            .named(
+                "types",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "listen only for events of the given types (key, mouse, resize); defaults to all",
+                Some('t'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The terminal is put into raw mode while listening, so no key is echoed and
+ctrl-c arrives as a regular key event. The returned record always has a
+'type' column (key, mouse or resize) plus the fields of that event:
+    key:    code, modifiers
+    mouse:  col, row, kind, modifiers
+    resize: columns, rows"#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let filter = EventTypeFilter::from_call(engine_state, stack, call)?;
+
+        terminal::enable_raw_mode()?;
+        if filter.mouse {
+            execute!(stdout(), EnableMouseCapture)?;
+        }
+
+        let result = listen_for_event(&filter, head);
+
+        if filter.mouse {
+            let _ = execute!(stdout(), DisableMouseCapture);
+        }
+        terminal::disable_raw_mode()?;
+
+        Ok(result?.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the next keyboard or mouse event",
+                example: "input listen",
+                result: None,
+            },
+            Example {
+                description: "Wait for a key press, ignoring mouse and resize events",
+                example: "input listen --types [key]",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct EventTypeFilter {
+    key: bool,
+    mouse: bool,
+    resize: bool,
+}
+
+impl EventTypeFilter {
+    fn from_call(
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+    ) -> Result<EventTypeFilter, ShellError> {
+        let types: Option<Value> = call.get_flag(engine_state, stack, "types")?;
+
+        match types {
+            None => Ok(EventTypeFilter {
+                key: true,
+                mouse: true,
+                resize: true,
+            }),
+            Some(types) => {
+                let mut filter = EventTypeFilter {
+                    key: false,
+                    mouse: false,
+                    resize: false,
+                };
+                for ty in types.as_list()? {
+                    let span = ty.span()?;
+                    match ty.as_string()?.as_str() {
+                        "key" => filter.key = true,
+                        "mouse" => filter.mouse = true,
+                        "resize" => filter.resize = true,
+                        unknown => {
+                            return Err(ShellError::SpannedLabeledError(
+                                format!("{} is not a valid event type", unknown),
+                                "expected key, mouse or resize".into(),
+                                span,
+                            ))
+                        }
+                    }
+                }
+                Ok(filter)
+            }
+        }
+    }
+}
+
+fn listen_for_event(filter: &EventTypeFilter, head: Span) -> Result<Value, ShellError> {
+    loop {
+        let event = crossterm::event::read()?;
+        if let Some(record) = event_to_record(event, filter, head) {
+            return Ok(record);
+        }
+    }
+}
+
+fn event_to_record(event: Event, filter: &EventTypeFilter, head: Span) -> Option<Value> {
+    match event {
+        Event::Key(KeyEvent { code, modifiers }) if filter.key => Some(Value::Record {
+            cols: vec!["type".into(), "code".into(), "modifiers".into()],
+            vals: vec![
+                Value::string("key", head),
+                Value::string(key_code_string(code), head),
+                modifiers_list(modifiers, head),
+            ],
+            span: head,
+        }),
+        Event::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers,
+        }) if filter.mouse => Some(Value::Record {
+            cols: vec![
+                "type".into(),
+                "col".into(),
+                "row".into(),
+                "kind".into(),
+                "modifiers".into(),
+            ],
+            vals: vec![
+                Value::string("mouse", head),
+                Value::int(column as i64, head),
+                Value::int(row as i64, head),
+                Value::string(format!("{:?}", kind), head),
+                modifiers_list(modifiers, head),
+            ],
+            span: head,
+        }),
+        Event::Resize(columns, rows) if filter.resize => Some(Value::Record {
+            cols: vec!["type".into(), "columns".into(), "rows".into()],
+            vals: vec![
+                Value::string("resize", head),
+                Value::int(columns as i64, head),
+                Value::int(rows as i64, head),
+            ],
+            span: head,
+        }),
+        _ => None,
+    }
+}
+
+fn key_code_string(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(c) => c.to_string(),
+        other => format!("{:?}", other).to_lowercase(),
+    }
+}
+
+fn modifiers_list(modifiers: KeyModifiers, head: Span) -> Value {
+    let mut vals = vec![];
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        vals.push(Value::string("shift", head));
+    }
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        vals.push(Value::string("control", head));
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        vals.push(Value::string("alt", head));
+    }
+    Value::List { vals, span: head }
+}
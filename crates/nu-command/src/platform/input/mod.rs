@@ -0,0 +1,5 @@
+mod input_;
+mod listen;
+
+pub use input_::Input;
+pub use listen::InputListen;
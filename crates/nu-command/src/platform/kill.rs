@@ -3,7 +3,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{ast::Call, span};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
-    Signature, Spanned, SyntaxShape, Value,
+    Signature, SyntaxShape, Value,
 };
 use std::process::{Command as CommandSys, Stdio};
 
@@ -35,12 +35,18 @@ impl Command for Kill {
             return signature;
         }
 
-        signature.named(
-            "signal",
-            SyntaxShape::Int,
-            "signal decimal number to be sent instead of the default 15 (unsupported on Windows)",
-            Some('s'),
-        )
+        signature
+            .named(
+                "signal",
+                SyntaxShape::Any,
+                "signal number or name to be sent instead of the default 15/TERM (unsupported on Windows)",
+                Some('s'),
+            )
+            .switch(
+                "process-group",
+                "kill the whole process group of each pid (unsupported on Windows)",
+                Some('g'),
+            )
     }
 
     fn run(
@@ -53,8 +59,9 @@ impl Command for Kill {
         let pid: i64 = call.req(engine_state, stack, 0)?;
         let rest: Vec<i64> = call.rest(engine_state, stack, 1)?;
         let force: bool = call.has_flag("force");
-        let signal: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "signal")?;
+        let signal: Option<Value> = call.get_flag(engine_state, stack, "signal")?;
         let quiet: bool = call.has_flag("quiet");
+        let process_group: bool = call.has_flag("process-group");
 
         let mut cmd = if cfg!(windows) {
             let mut cmd = CommandSys::new("taskkill");
@@ -77,11 +84,7 @@ impl Command for Kill {
         } else {
             let mut cmd = CommandSys::new("kill");
             if force {
-                if let Some(Spanned {
-                    item: _,
-                    span: signal_span,
-                }) = signal
-                {
+                if let Some(signal_value) = &signal {
                     return Err(ShellError::IncompatibleParameters {
                         left_message: "force".to_string(),
                         left_span: call
@@ -105,18 +108,35 @@ impl Command for Kill {
                                     )
                                 })?
                                 .span,
-                            signal_span,
+                            signal_value.span()?,
                         ]),
                     });
                 }
                 cmd.arg("-9");
             } else if let Some(signal_value) = signal {
-                cmd.arg(format!("-{}", signal_value.item));
+                // `kill` accepts both numbers and names, so pass names straight through
+                let signal_str = match signal_value {
+                    Value::Int { val, .. } => val.to_string(),
+                    Value::String { val, .. } => val.trim_start_matches('-').to_uppercase(),
+                    other => {
+                        return Err(ShellError::UnsupportedInput(
+                            "signal should be a number or a signal name like TERM".into(),
+                            other.span()?,
+                        ))
+                    }
+                };
+                cmd.arg(format!("-{}", signal_str));
             }
 
-            cmd.arg(pid.to_string());
-
-            cmd.args(rest.iter().map(move |id| id.to_string()));
+            if process_group {
+                // a leading `-` makes kill target the whole process group
+                cmd.arg("--");
+                cmd.arg(format!("-{}", pid));
+                cmd.args(rest.iter().map(move |id| format!("-{}", id)));
+            } else {
+                cmd.arg(pid.to_string());
+                cmd.args(rest.iter().map(move |id| id.to_string()));
+            }
 
             cmd
         };
@@ -163,6 +183,16 @@ impl Command for Kill {
                 example: "kill -s 2 12345",
                 result: None,
             },
+            Example {
+                description: "Send HUP signal by name",
+                example: "kill --signal HUP 12345",
+                result: None,
+            },
+            Example {
+                description: "Kill the whole process group of a pid",
+                example: "kill --process-group 12345",
+                result: None,
+            },
         ]
     }
 }
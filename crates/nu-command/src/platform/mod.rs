@@ -1,19 +1,27 @@
 mod ansi;
 mod clear;
+#[cfg(feature = "clipboard-cli")]
+mod clip;
 mod dir_info;
 mod du;
 mod input;
 mod kill;
+#[cfg(feature = "clipboard-cli")]
+mod paste;
 mod reedline_commands;
 mod sleep;
 mod term_size;
 
 pub use ansi::{Ansi, AnsiGradient, AnsiStrip};
 pub use clear::Clear;
+#[cfg(feature = "clipboard-cli")]
+pub use clip::Clip;
 pub use dir_info::{DirBuilder, DirInfo, FileInfo};
 pub use du::Du;
-pub use input::Input;
+pub use input::{Input, InputListen};
 pub use kill::Kill;
+#[cfg(feature = "clipboard-cli")]
+pub use paste::Paste;
 pub use reedline_commands::{Keybindings, KeybindingsDefault, KeybindingsList, KeybindingsListen};
 pub use sleep::Sleep;
 pub use term_size::TermSize;
@@ -0,0 +1,62 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Paste;
+
+impl Command for Paste {
+    fn name(&self) -> &str {
+        "paste"
+    }
+
+    fn usage(&self) -> &str {
+        "Read the system clipboard as a string value."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("paste").category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let mut clipboard = arboard::Clipboard::new().map_err(|err| {
+            ShellError::SpannedLabeledError(
+                "Could not access the system clipboard".into(),
+                err.to_string(),
+                head,
+            )
+        })?;
+
+        let contents = clipboard.get_text().map_err(|err| {
+            ShellError::SpannedLabeledError(
+                "Could not read from the system clipboard".into(),
+                err.to_string(),
+                head,
+            )
+        })?;
+
+        Ok(Value::String {
+            val: contents,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Paste the clipboard contents into the pipeline",
+            example: "paste | from json",
+            result: None,
+        }]
+    }
+}
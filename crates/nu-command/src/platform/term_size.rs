@@ -1,6 +1,6 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, IntoPipelineData, PipelineData, Signature, Span, Value};
+use nu_protocol::{Category, Example, IntoPipelineData, PipelineData, Signature, Value};
 use terminal_size::{terminal_size, Height, Width};
 
 #[derive(Clone)]
@@ -63,46 +63,24 @@ impl Command for TermSize {
         };
 
         Ok((match (wide, tall) {
-            (true, false) => Value::Record {
-                cols: vec!["columns".into()],
-                vals: vec![Value::Int {
-                    val: cols.0 as i64,
-                    span: Span::test_data(),
-                }],
+            (true, false) => Value::Int {
+                val: cols.0 as i64,
                 span: head,
             },
-            (true, true) => Value::Record {
-                cols: vec!["columns".into(), "rows".into()],
-                vals: vec![
-                    Value::Int {
-                        val: cols.0 as i64,
-                        span: Span::test_data(),
-                    },
-                    Value::Int {
-                        val: rows.0 as i64,
-                        span: Span::test_data(),
-                    },
-                ],
-                span: head,
-            },
-            (false, true) => Value::Record {
-                cols: vec!["rows".into()],
-                vals: vec![Value::Int {
-                    val: rows.0 as i64,
-                    span: Span::test_data(),
-                }],
+            (false, true) => Value::Int {
+                val: rows.0 as i64,
                 span: head,
             },
-            (false, false) => Value::Record {
+            _ => Value::Record {
                 cols: vec!["columns".into(), "rows".into()],
                 vals: vec![
                     Value::Int {
                         val: cols.0 as i64,
-                        span: Span::test_data(),
+                        span: head,
                     },
                     Value::Int {
                         val: rows.0 as i64,
-                        span: Span::test_data(),
+                        span: head,
                     },
                 ],
                 span: head,
@@ -101,6 +101,10 @@ impl Command for Enter {
             },
         );
 
+        if let Some(oldpwd) = stack.get_env_var(engine_state, "PWD") {
+            stack.add_env_var("OLDPWD".into(), oldpwd)
+        }
+
         stack.add_env_var("PWD".into(), new_path);
 
         Ok(PipelineData::new(call.head))
@@ -73,6 +73,10 @@ impl Command for GotoShell {
             },
         );
 
+        if let Some(oldpwd) = stack.get_env_var(engine_state, "PWD") {
+            stack.add_env_var("OLDPWD".into(), oldpwd)
+        }
+
         stack.add_env_var("PWD".into(), new_path);
 
         Ok(PipelineData::new(call.head))
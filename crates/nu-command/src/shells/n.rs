@@ -72,6 +72,10 @@ impl Command for NextShell {
             },
         );
 
+        if let Some(oldpwd) = stack.get_env_var(engine_state, "PWD") {
+            stack.add_env_var("OLDPWD".into(), oldpwd)
+        }
+
         stack.add_env_var("PWD".into(), new_path);
 
         Ok(PipelineData::new(call.head))
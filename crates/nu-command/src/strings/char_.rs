@@ -108,6 +108,23 @@ lazy_static! {
         "high_voltage_sign" => '\u{26a1}'.to_string(),             // ⚡
         "elevated" => '\u{26a1}'.to_string(),                      // ⚡
 
+        // Box drawing (U+2500-U+257F)
+        "horizontal" => '\u{2500}'.to_string(),                    // ─
+        "vertical" => '\u{2502}'.to_string(),                      // │
+        "down_and_right" => '\u{250c}'.to_string(),                // ┌
+        "top_left" => '\u{250c}'.to_string(),                      // ┌
+        "down_and_left" => '\u{2510}'.to_string(),                 // ┐
+        "top_right" => '\u{2510}'.to_string(),                     // ┐
+        "up_and_right" => '\u{2514}'.to_string(),                  // └
+        "bottom_left" => '\u{2514}'.to_string(),                   // └
+        "up_and_left" => '\u{2518}'.to_string(),                   // ┘
+        "bottom_right" => '\u{2518}'.to_string(),                  // ┘
+        "vertical_and_right" => '\u{251c}'.to_string(),            // ├
+        "vertical_and_left" => '\u{2524}'.to_string(),             // ┤
+        "down_and_horizontal" => '\u{252c}'.to_string(),           // ┬
+        "up_and_horizontal" => '\u{2534}'.to_string(),             // ┴
+        "vertical_and_horizontal" => '\u{253c}'.to_string(),       // ┼
+
         // This is the emoji section
         // Weather symbols
         // https://www.babelstone.co.uk/Unicode/whatisit.html
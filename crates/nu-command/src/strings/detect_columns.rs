@@ -6,7 +6,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    Spanned, SyntaxShape, Value,
+    Spanned, SyntaxShape, Type, Value,
 };
 
 type Input<'t> = Peekable<CharIndices<'t>>;
@@ -21,6 +21,7 @@ impl Command for DetectColumns {
 
     fn signature(&self) -> Signature {
         Signature::build("detect columns")
+            .input_type(Type::String)
             .named(
                 "skip",
                 SyntaxShape::Int,
@@ -0,0 +1,148 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    format_filesize, Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value,
+};
+
+struct Arguments {
+    format_value: String,
+    column_paths: Vec<CellPath>,
+    filesize_metric: bool,
+}
+
+#[derive(Clone)]
+pub struct FileSize;
+
+impl Command for FileSize {
+    fn name(&self) -> &str {
+        "format filesize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format filesize")
+            .required(
+                "format value",
+                SyntaxShape::String,
+                "the format into which convert the file sizes",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "column paths to convert (for table input)",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Converts a column of filesizes to some specified format"
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["convert", "display", "pattern", "human readable"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let config = stack.get_config()?;
+        let format_value = call
+            .req::<Value>(engine_state, stack, 0)?
+            .as_string()?
+            .to_lowercase();
+        let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+
+        let arg = Arguments {
+            format_value,
+            column_paths,
+            filesize_metric: config.filesize_metric,
+        };
+
+        format_value_impl(engine_state, call, input, arg)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert the size row to KB",
+                example: "ls | format filesize KB size",
+                result: None,
+            },
+            Example {
+                description: "Convert the apparent row to B",
+                example: "du | format filesize B apparent",
+                result: None,
+            },
+            Example {
+                description: "Convert the size data to MB",
+                example: "4GB | format filesize MB",
+                result: Some(Value::test_string("4000.0 MB")),
+            },
+        ]
+    }
+}
+
+fn format_value_impl(
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+    arg: Arguments,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+
+    input.map(
+        move |v| {
+            if arg.column_paths.is_empty() {
+                action(&v, &arg.format_value, arg.filesize_metric, head)
+            } else {
+                let mut ret = v;
+                for path in &arg.column_paths {
+                    let format_value = arg.format_value.clone();
+                    let filesize_metric = arg.filesize_metric;
+                    let r = ret.update_cell_path(
+                        &path.members,
+                        Box::new(move |old| action(old, &format_value, filesize_metric, head)),
+                    );
+                    if let Err(error) = r {
+                        return Value::Error { error };
+                    }
+                }
+
+                ret
+            }
+        },
+        engine_state.ctrlc.clone(),
+    )
+}
+
+fn action(input: &Value, format_value: &str, filesize_metric: bool, span: Span) -> Value {
+    match input {
+        Value::Filesize { val, .. } => Value::String {
+            val: format_filesize(*val, format_value, filesize_metric),
+            span,
+        },
+        _ => Value::Error {
+            error: ShellError::UnsupportedInput(
+                "'format filesize' only works with filesize values".into(),
+                span,
+            ),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FileSize {})
+    }
+}
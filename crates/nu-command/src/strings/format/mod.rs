@@ -1,3 +1,5 @@
 pub mod command;
+mod filesize;
 
 pub use command::Format;
+pub use filesize::FileSize;
@@ -1,11 +1,11 @@
-use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
-    Value,
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
 };
 
+use super::join::StrJoin;
+
 #[derive(Clone)]
 pub struct StrCollect;
 
@@ -25,7 +25,7 @@ impl Command for StrCollect {
     }
 
     fn usage(&self) -> &str {
-        "Concatenate multiple strings into a single string, with an optional separator between each"
+        "Concatenate multiple strings into a single string, with an optional separator between each. Alias of `str join`"
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -39,37 +39,7 @@ impl Command for StrCollect {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let separator: Option<String> = call.opt(engine_state, stack, 0)?;
-
-        let config = stack.get_config().unwrap_or_default();
-
-        // let output = input.collect_string(&separator.unwrap_or_default(), &config)?;
-        // Hmm, not sure what we actually want. If you don't use debug_string, Date comes out as human readable
-        // which feels funny
-        let mut strings: Vec<String> = vec![];
-
-        for value in input {
-            match value {
-                Value::Error { error } => {
-                    return Err(error);
-                }
-                value => {
-                    strings.push(value.debug_string("\n", &config));
-                }
-            }
-        }
-
-        let output = if let Some(separator) = separator {
-            strings.join(&separator)
-        } else {
-            strings.join("")
-        };
-
-        Ok(Value::String {
-            val: output,
-            span: call.head,
-        }
-        .into_pipeline_data())
+        StrJoin.run(engine_state, stack, call, input)
     }
 
     fn examples(&self) -> Vec<Example> {
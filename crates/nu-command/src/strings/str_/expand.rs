@@ -0,0 +1,226 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::ast::CellPath;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::Category;
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str expand"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str expand")
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "optionally expand text by column paths",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Generate all possible combinations defined in brace expansion syntax"
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["brace", "expansion", "combinations"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        operate(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Define a range of values using braces",
+                example: "'file{1,2,3}.txt' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::String {
+                            val: "file1.txt".to_string(),
+                            span: Span::test_data(),
+                        },
+                        Value::String {
+                            val: "file2.txt".to_string(),
+                            span: Span::test_data(),
+                        },
+                        Value::String {
+                            val: "file3.txt".to_string(),
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Brace expressions can be nested",
+                example: "'a{b{1,2},c}' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::String {
+                            val: "ab1".to_string(),
+                            span: Span::test_data(),
+                        },
+                        Value::String {
+                            val: "ab2".to_string(),
+                            span: Span::test_data(),
+                        },
+                        Value::String {
+                            val: "ac".to_string(),
+                            span: Span::test_data(),
+                        },
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "A string without braces expands to itself",
+                example: "'plain' | str expand",
+                result: Some(Value::List {
+                    vals: vec![Value::String {
+                        val: "plain".to_string(),
+                        span: Span::test_data(),
+                    }],
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+fn operate(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+    input.map(
+        move |v| {
+            if column_paths.is_empty() {
+                action(&v, head)
+            } else {
+                let mut ret = v;
+                for path in &column_paths {
+                    let r =
+                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    if let Err(error) = r {
+                        return Value::Error { error };
+                    }
+                }
+                ret
+            }
+        },
+        engine_state.ctrlc.clone(),
+    )
+}
+
+fn action(input: &Value, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::List {
+            vals: expand_string(val)
+                .into_iter()
+                .map(|val| Value::String { val, span: head })
+                .collect(),
+            span: head,
+        },
+        other => Value::Error {
+            error: ShellError::UnsupportedInput(
+                format!(
+                    "Input's type is {}. This command only works with strings.",
+                    other.get_type()
+                ),
+                head,
+            ),
+        },
+    }
+}
+
+/// Expand the leftmost outermost brace group and recurse into the results
+/// until no groups are left.
+fn expand_string(contents: &str) -> Vec<String> {
+    match split_brace_group(contents) {
+        Some((prefix, options, suffix)) => options
+            .iter()
+            .flat_map(|option| expand_string(&format!("{}{}{}", prefix, option, suffix)))
+            .collect(),
+        None => vec![contents.to_string()],
+    }
+}
+
+/// Split `contents` around its first balanced brace group, returning the text
+/// before the group, the comma separated options inside it, and the text after
+/// it. Commas and braces inside nested groups are left intact so recursion can
+/// pick them up. Unbalanced braces are treated as literal text.
+fn split_brace_group(contents: &str) -> Option<(&str, Vec<&str>, &str)> {
+    let open = contents.find('{')?;
+
+    let mut depth = 0;
+    let mut options = vec![];
+    let mut option_start = open + '{'.len_utf8();
+
+    for (idx, ch) in contents[open..].char_indices() {
+        let idx = open + idx;
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    options.push(&contents[option_start..idx]);
+                    return Some((
+                        &contents[..open],
+                        options,
+                        &contents[idx + '}'.len_utf8()..],
+                    ));
+                }
+            }
+            ',' if depth == 1 => {
+                options.push(&contents[option_start..idx]);
+                option_start = idx + ','.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn expands_multiple_groups() {
+        assert_eq!(
+            expand_string("{a,b}{1,2}"),
+            vec!["a1", "a2", "b1", "b2"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn leaves_unbalanced_braces_alone() {
+        assert_eq!(expand_string("file{1,2.txt"), vec!["file{1,2.txt"]);
+    }
+}
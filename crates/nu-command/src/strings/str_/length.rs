@@ -4,6 +4,7 @@ use nu_protocol::ast::CellPath;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::Category;
 use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -15,6 +16,11 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("str length")
+            .switch(
+                "grapheme-clusters",
+                "count length using grapheme clusters (all visible chars have length 1)",
+                Some('g'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -44,6 +50,11 @@ impl Command for SubCommand {
                 example: "'hello' | str length",
                 result: Some(Value::test_int(5)),
             },
+            Example {
+                description: "Count length using grapheme clusters",
+                example: "'🇯🇵ほげ' | str length -g",
+                result: Some(Value::test_int(3)),
+            },
             Example {
                 description: "Return the lengths of multiple strings",
                 example: "['hi' 'there'] | str length",
@@ -64,15 +75,18 @@ fn operate(
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
     let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+    let graphemes = call.has_flag("grapheme-clusters");
     input.map(
         move |v| {
             if column_paths.is_empty() {
-                action(&v, head)
+                action(&v, graphemes, head)
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    let r = ret.update_cell_path(
+                        &path.members,
+                        Box::new(move |old| action(old, graphemes, head)),
+                    );
                     if let Err(error) = r {
                         return Value::Error { error };
                     }
@@ -84,10 +98,14 @@ fn operate(
     )
 }
 
-fn action(input: &Value, head: Span) -> Value {
+fn action(input: &Value, graphemes: bool, head: Span) -> Value {
     match input {
         Value::String { val, .. } => Value::Int {
-            val: val.len() as i64,
+            val: if graphemes {
+                val.graphemes(true).count() as i64
+            } else {
+                val.len() as i64
+            },
             span: head,
         },
         other => Value::Error {
@@ -4,7 +4,9 @@ mod collect;
 mod contains;
 mod downcase;
 mod ends_with;
+mod expand;
 mod index_of;
+mod join;
 mod length;
 mod lpad;
 mod replace;
@@ -21,7 +23,9 @@ pub use collect::*;
 pub use contains::SubCommand as StrContains;
 pub use downcase::SubCommand as StrDowncase;
 pub use ends_with::SubCommand as StrEndswith;
+pub use expand::SubCommand as StrExpand;
 pub use index_of::SubCommand as StrIndexOf;
+pub use join::StrJoin;
 pub use length::SubCommand as StrLength;
 pub use lpad::SubCommand as StrLpad;
 pub use replace::SubCommand as StrReplace;
@@ -5,6 +5,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
 use std::cmp::Ordering;
 use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -12,6 +13,7 @@ pub struct SubCommand;
 struct Arguments {
     range: Value,
     column_paths: Vec<CellPath>,
+    graphemes: bool,
 }
 
 #[derive(Clone)]
@@ -32,6 +34,11 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("str substring")
+            .switch(
+                "grapheme-clusters",
+                "count indexes using grapheme clusters (all visible chars have length 1)",
+                Some('g'),
+            )
             .required(
                 "range",
                 SyntaxShape::Any,
@@ -85,6 +92,11 @@ impl Command for SubCommand {
                 example: " 'good nushell' | str substring ',7'",
                 result: Some(Value::test_string("good nu")),
             },
+            Example {
+                description: "Count indexes using grapheme clusters",
+                example: " '🇯🇵ほげ ふが ぴよ' | str substring -g [4 5]",
+                result: Some(Value::test_string("ふ")),
+            },
         ]
     }
 }
@@ -98,6 +110,7 @@ fn operate(
     let options = Arc::new(Arguments {
         range: call.req(engine_state, stack, 0)?,
         column_paths: call.rest(engine_state, stack, 1)?,
+        graphemes: call.has_flag("grapheme-clusters"),
     });
 
     let head = call.head;
@@ -106,14 +119,15 @@ fn operate(
     input.map(
         move |v| {
             if options.column_paths.is_empty() {
-                action(&v, &indexes, head)
+                action(&v, &indexes, options.graphemes, head)
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
                     let indexes = indexes.clone();
+                    let graphemes = options.graphemes;
                     let r = ret.update_cell_path(
                         &path.members,
-                        Box::new(move |old| action(old, &indexes, head)),
+                        Box::new(move |old| action(old, &indexes, graphemes, head)),
                     );
                     if let Err(error) = r {
                         return Value::Error { error };
@@ -126,10 +140,14 @@ fn operate(
     )
 }
 
-fn action(input: &Value, options: &Substring, head: Span) -> Value {
+fn action(input: &Value, options: &Substring, graphemes: bool, head: Span) -> Value {
     match input {
         Value::String { val: s, .. } => {
-            let len: isize = s.len() as isize;
+            let len: isize = if graphemes {
+                s.graphemes(true).count() as isize
+            } else {
+                s.len() as isize
+            };
 
             let start: isize = if options.0 < 0 {
                 options.0 + len
@@ -156,7 +174,16 @@ fn action(input: &Value, options: &Substring, head: Span) -> Value {
                     },
                     Ordering::Less => Value::String {
                         val: {
-                            if end == isize::max_value() {
+                            if graphemes {
+                                if end == isize::max_value() {
+                                    s.graphemes(true).skip(start as usize).collect()
+                                } else {
+                                    s.graphemes(true)
+                                        .skip(start as usize)
+                                        .take((end - start) as usize)
+                                        .collect()
+                                }
+                            } else if end == isize::max_value() {
                                 String::from_utf8_lossy(
                                     &s.bytes().skip(start as usize).collect::<Vec<_>>(),
                                 )
@@ -307,6 +334,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn substrings_graphemes() {
+        let word = Value::String {
+            val: "🇯🇵ほげ".to_string(),
+            span: Span::test_data(),
+        };
+
+        let actual = action(&word, &Substring(1, 2), true, Span::test_data());
+
+        assert_eq!(
+            actual,
+            Value::String {
+                val: "ほ".to_string(),
+                span: Span::test_data()
+            }
+        );
+    }
+
     #[test]
     fn substrings_indexes() {
         let word = Value::String {
@@ -342,7 +387,7 @@ mod tests {
 
         for expectation in &cases {
             let expected = expectation.expected;
-            let actual = action(&word, &expectation.options(), Span::test_data());
+            let actual = action(&word, &expectation.options(), false, Span::test_data());
 
             assert_eq!(
                 actual,
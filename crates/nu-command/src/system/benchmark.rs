@@ -4,7 +4,8 @@ use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{CaptureBlock, Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, Signature, SyntaxShape, Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Value,
 };
 
 #[derive(Clone)]
@@ -19,6 +20,10 @@ impl Command for Benchmark {
         "Time the running time of a block"
     }
 
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["timeit", "time", "duration"]
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("benchmark")
             .required(
@@ -26,6 +31,12 @@ impl Command for Benchmark {
                 SyntaxShape::Block(Some(vec![])),
                 "the block to run",
             )
+            .named(
+                "rounds",
+                SyntaxShape::Int,
+                "run the block this many times and return a list of durations",
+                Some('r'),
+            )
             .category(Category::System)
     }
 
@@ -34,41 +45,86 @@ impl Command for Benchmark {
         engine_state: &EngineState,
         stack: &mut Stack,
         call: &Call,
-        _input: PipelineData,
+        input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let capture_block: CaptureBlock = call.req(engine_state, stack, 0)?;
         let block = engine_state.get_block(capture_block.block_id);
 
+        let rounds = call.get_flag::<Spanned<i64>>(engine_state, stack, "rounds")?;
+        if let Some(Spanned { item, span }) = &rounds {
+            if *item < 1 {
+                return Err(ShellError::SpannedLabeledError(
+                    "expected a positive number of rounds".into(),
+                    "value should be at least 1".into(),
+                    *span,
+                ));
+            }
+        }
+
         let redirect_stdout = call.redirect_stdout;
         let redirect_stderr = call.redirect_stderr;
 
         let mut stack = stack.captures_to_stack(&capture_block.captures);
-        let start_time = Instant::now();
-        eval_block(
-            engine_state,
-            &mut stack,
-            block,
-            PipelineData::new(call.head),
-            redirect_stdout,
-            redirect_stderr,
-        )?
-        .into_value(call.head);
-
-        let end_time = Instant::now();
-
-        let output = Value::Duration {
-            val: (end_time - start_time).as_nanos() as i64,
-            span: call.head,
+
+        // Collect the input before the timer starts so that we only measure the block itself
+        let input_val = input.into_value(call.head);
+        if let Some(var) = block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                stack.add_var(*var_id, input_val.clone());
+            }
+        }
+
+        let mut durations = vec![];
+        for _ in 0..rounds.as_ref().map_or(1, |rounds| rounds.item) {
+            let start_time = Instant::now();
+            eval_block(
+                engine_state,
+                &mut stack,
+                block,
+                input_val.clone().into_pipeline_data(),
+                redirect_stdout,
+                redirect_stderr,
+            )?
+            .into_value(call.head);
+
+            let end_time = Instant::now();
+
+            durations.push(Value::Duration {
+                val: (end_time - start_time).as_nanos() as i64,
+                span: call.head,
+            });
+        }
+
+        // Without --rounds keep returning a single duration rather than a list of one
+        let output = if rounds.is_some() {
+            Value::List {
+                vals: durations,
+                span: call.head,
+            }
+        } else {
+            durations.remove(0)
         };
 
         Ok(output.into_pipeline_data())
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Benchmarks a command within a block",
-            example: "benchmark { sleep 500ms }",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Benchmarks a command within a block",
+                example: "benchmark { sleep 500ms }",
+                result: None,
+            },
+            Example {
+                description: "Benchmarks a command using an input value",
+                example: "echo 2 | benchmark { $in | math sqrt }",
+                result: None,
+            },
+            Example {
+                description: "Benchmarks a command several times and average the results",
+                example: "benchmark --rounds 10 { sleep 100ms } | math avg",
+                result: None,
+            },
+        ]
     }
 }
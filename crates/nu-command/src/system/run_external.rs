@@ -80,14 +80,21 @@ impl Command for External {
             })
             .collect::<Result<Vec<Spanned<String>>, ShellError>>()?;
 
+        let (args, redirection) = extract_redirection(args)?;
+
         let command = ExternalCommand {
             name,
             args,
-            redirect_stdout,
-            redirect_stderr,
+            redirect_stdout: redirect_stdout || matches!(redirection, Some((true, _))),
+            redirect_stderr: redirect_stderr || redirection.is_some(),
             env_vars: env_vars_str,
         };
-        command.run_with_input(engine_state, stack, input)
+        let output = command.run_with_input(engine_state, stack, input)?;
+
+        match redirection {
+            None => Ok(output),
+            Some((include_stdout, target)) => redirect_to_file(output, include_stdout, target),
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -99,6 +106,101 @@ impl Command for External {
     }
 }
 
+/// Split a trailing `err> target` or `out+err> target` redirection off the
+/// argument list. The bool is true when stdout is redirected as well
+#[allow(clippy::type_complexity)]
+fn extract_redirection(
+    mut args: Vec<Spanned<String>>,
+) -> Result<(Vec<Spanned<String>>, Option<(bool, Spanned<String>)>), ShellError> {
+    let pos = match args
+        .iter()
+        .position(|arg| arg.item == "err>" || arg.item == "out+err>")
+    {
+        Some(pos) => pos,
+        None => return Ok((args, None)),
+    };
+
+    if pos + 2 != args.len() {
+        return Err(ShellError::ExternalCommand(
+            "Invalid redirection".into(),
+            "expected a single file name after the redirection".into(),
+            args[pos].span,
+        ));
+    }
+
+    let target = args.pop().expect("already checked args length");
+    let marker = args.remove(pos);
+
+    Ok((args, Some((marker.item == "out+err>", target))))
+}
+
+/// Send the external's stderr (and with `out+err>` its stdout too) to the
+/// named file, leaving the rest of the stream in the pipeline
+fn redirect_to_file(
+    output: PipelineData,
+    include_stdout: bool,
+    target: Spanned<String>,
+) -> Result<PipelineData, ShellError> {
+    let mut file = std::fs::File::create(&target.item).map_err(|err| {
+        ShellError::ExternalCommand(
+            format!("Could not create '{}'", target.item),
+            err.to_string(),
+            target.span,
+        )
+    })?;
+
+    match output {
+        PipelineData::ExternalStream {
+            stdout,
+            stderr,
+            exit_code,
+            span,
+            metadata,
+        } => {
+            // The reader thread sends all of stderr before any of stdout, so
+            // draining in that order cannot deadlock
+            if let Some(stream) = stderr {
+                drain_to_file(stream, &mut file, target.span)?;
+            }
+
+            let stdout = match stdout {
+                Some(stream) if include_stdout => {
+                    drain_to_file(stream, &mut file, target.span)?;
+                    None
+                }
+                stdout => stdout,
+            };
+
+            Ok(PipelineData::ExternalStream {
+                stdout,
+                stderr: None,
+                exit_code,
+                span,
+                metadata,
+            })
+        }
+        other => Ok(other),
+    }
+}
+
+fn drain_to_file(
+    stream: RawStream,
+    file: &mut std::fs::File,
+    span: Span,
+) -> Result<(), ShellError> {
+    for bytes in stream.stream {
+        file.write_all(&bytes?).map_err(|err| {
+            ShellError::ExternalCommand(
+                "Failed to write to the redirection file".into(),
+                err.to_string(),
+                span,
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
 pub struct ExternalCommand {
     pub name: Spanned<String>,
     pub args: Vec<Spanned<String>>,
@@ -119,6 +221,13 @@ impl ExternalCommand {
         let ctrlc = engine_state.ctrlc.clone();
 
         let mut process = self.create_process(&input, false, head)?;
+
+        // Run the external in its own process group so an interrupt from the
+        // terminal reaches it and everything it spawned, and nothing lingers
+        // behind the prompt after ctrl-c
+        #[cfg(unix)]
+        foreground::prepare_command(&mut process);
+
         let child;
 
         #[cfg(windows)]
@@ -146,6 +255,9 @@ impl ExternalCommand {
                 self.name.span,
             )),
             Ok(mut child) => {
+                #[cfg(unix)]
+                foreground::set(child.id());
+
                 if !input.is_nothing() {
                     let engine_state = engine_state.clone();
                     let mut stack = stack.clone();
@@ -225,6 +337,9 @@ impl ExternalCommand {
 
                             if let Some(ctrlc) = &ctrlc {
                                 if ctrlc.load(Ordering::SeqCst) {
+                                    // Don't leave the external running behind
+                                    // the returned prompt
+                                    let _ = child.kill();
                                     break;
                                 }
                             }
@@ -264,6 +379,9 @@ impl ExternalCommand {
 
                             if let Some(ctrlc) = &ctrlc {
                                 if ctrlc.load(Ordering::SeqCst) {
+                                    // Don't leave the external running behind
+                                    // the returned prompt
+                                    let _ = child.kill();
                                     break;
                                 }
                             }
@@ -275,7 +393,13 @@ impl ExternalCommand {
                         }
                     }
 
-                    match child.wait() {
+                    let wait_result = child.wait();
+
+                    // Hand the terminal back to the shell before reporting
+                    #[cfg(unix)]
+                    foreground::reset();
+
+                    match wait_result {
                         Err(err) => Err(ShellError::ExternalCommand(
                             "External command exited with error".into(),
                             err.to_string(),
@@ -571,3 +695,42 @@ impl Iterator for ValueReceiver {
         }
     }
 }
+
+// Process-group management for externals. Each spawned external is placed in
+// its own process group, which is made the terminal's foreground group while
+// it runs: ctrl-c is then delivered by the terminal to the whole group --
+// including any processes the external itself spawned -- instead of to nu
+#[cfg(unix)]
+mod foreground {
+    use std::process::Command;
+
+    pub fn prepare_command(process: &mut Command) {
+        use std::os::unix::process::CommandExt;
+        unsafe {
+            process.pre_exec(|| {
+                libc::setpgid(0, 0);
+                Ok(())
+            });
+        }
+    }
+
+    pub fn set(pid: u32) {
+        let pid = pid as libc::pid_t;
+        unsafe {
+            // Set the group from this side as well, so there is no window
+            // between the fork and the child's own setpgid call
+            libc::setpgid(pid, pid);
+
+            // A process that is not in the foreground group gets stopped by
+            // SIGTTOU when it calls tcsetpgrp, unless the signal is ignored
+            libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+            libc::tcsetpgrp(libc::STDIN_FILENO, pid);
+        }
+    }
+
+    pub fn reset() {
+        unsafe {
+            libc::tcsetpgrp(libc::STDIN_FILENO, libc::getpgrp());
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{Local, TimeZone};
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
@@ -293,6 +294,15 @@ pub fn host(sys: &mut System, span: Span) -> Option<Value> {
         span,
     });
 
+    // boot time is indicated as seconds since unix epoch
+    if let Some(boot_time) = Local.timestamp_opt(sys.boot_time() as i64, 0).single() {
+        cols.push("boot time".into());
+        vals.push(Value::Date {
+            val: boot_time.with_timezone(boot_time.offset()),
+            span,
+        });
+    }
+
     let mut users = vec![];
     for user in sys.users() {
         let mut cols = vec![];
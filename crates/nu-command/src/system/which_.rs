@@ -43,11 +43,18 @@ impl Command for Which {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Find if the 'myapp' application is available",
-            example: "which myapp",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Find if the 'myapp' application is available",
+                example: "which myapp",
+                result: None,
+            },
+            Example {
+                description: "Find every match for 'ls', including anything shadowing it",
+                example: "which --all ls",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -89,12 +96,37 @@ fn get_entry_in_aliases(engine_state: &EngineState, name: &str, span: Span) -> O
     }
 }
 
+// Find the file a definition span falls into, along with its 1-based line number
+fn find_definition_location(engine_state: &EngineState, span: Span) -> Option<(String, usize)> {
+    for (file_id, (filename, start, end)) in engine_state.files().enumerate() {
+        if span.start >= *start && span.start < *end {
+            let source = engine_state.get_file_source(file_id);
+            let line = source[..span.start - start].matches('\n').count() + 1;
+            return Some((filename.clone(), line));
+        }
+    }
+
+    None
+}
+
 fn get_entry_in_commands(engine_state: &EngineState, name: &str, span: Span) -> Option<Value> {
     if let Some(decl_id) = engine_state.find_decl(name.as_bytes()) {
-        let (msg, is_builtin) = if engine_state.get_decl(decl_id).get_block_id().is_some() {
-            ("Nushell custom command", false)
-        } else {
-            ("Nushell built-in command", true)
+        let (msg, is_builtin) = match engine_state.get_decl(decl_id).get_block_id() {
+            Some(block_id) => {
+                let location = engine_state
+                    .get_block(block_id)
+                    .span
+                    .and_then(|block_span| find_definition_location(engine_state, block_span));
+
+                match location {
+                    Some((filename, line)) => (
+                        format!("Nushell custom command ({}:{})", filename, line),
+                        false,
+                    ),
+                    None => ("Nushell custom command".to_string(), false),
+                }
+            }
+            None => ("Nushell built-in command".to_string(), true),
         };
 
         trace!("Found command: {}", name);
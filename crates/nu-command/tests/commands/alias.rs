@@ -12,6 +12,30 @@ fn alias_simple() {
     assert_eq!(actual.out, "hello");
 }
 
+#[test]
+fn alias_can_expand_to_a_pipeline() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        alias firstof = echo [1 2 3 4 5] | first; firstof 2 | math sum
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn alias_pipeline_receives_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        alias smallest = sort | first; echo [3 1 2] | smallest
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
 #[test]
 fn alias_hiding1() {
     let actual = nu!(
@@ -59,6 +59,25 @@ fn filesystem_switch_back_to_previous_working_directory() {
     })
 }
 
+#[test]
+fn filesystem_switch_back_after_shell_navigation() {
+    Playground::setup("cd_test_oldpwd_shells", |dirs, sandbox| {
+        sandbox.mkdir("odin");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"
+                enter odin
+                n
+                cd -
+                echo $env.PWD
+            "#
+        );
+
+        assert_eq!(PathBuf::from(actual.out), dirs.test().join("odin"));
+    })
+}
+
 // FIXME: jt: needs more work
 #[ignore]
 #[test]
@@ -0,0 +1,45 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn detects_headers_from_first_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(r#"
+            echo $'c1 c2 c3(char nl)a b c' | detect columns | get c2.0
+        "#)
+    );
+
+    assert_eq!(actual.out, "b");
+}
+
+#[test]
+fn no_headers_generates_column_names() {
+    let actual = nu!(
+        cwd: ".", pipeline(r#"
+            echo 'a b c' | detect columns --no-headers | get column2.0
+        "#)
+    );
+
+    assert_eq!(actual.out, "c");
+}
+
+#[test]
+fn skips_rows_before_detecting() {
+    let actual = nu!(
+        cwd: ".", pipeline(r#"
+            echo $'some banner(char nl)c1 c2 c3(char nl)a b c' | detect columns --skip 1 | get c3.0
+        "#)
+    );
+
+    assert_eq!(actual.out, "c");
+}
+
+#[test]
+fn aligns_short_rows_to_header_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(r#"
+            echo $'c1 c2 c3(char nl)a b' | detect columns | get c3.0 | empty?
+        "#)
+    );
+
+    assert_eq!(actual.out, "true");
+}
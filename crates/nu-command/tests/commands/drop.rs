@@ -32,6 +32,38 @@ fn more_columns_than_table_has() {
     assert_eq!(actual.out, "true");
 }
 
+#[test]
+fn single_column_by_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(r#"
+            echo [
+              [arepas, color];
+              [3,  white]
+              [8, yellow]
+              [4,  white]
+            ] | drop column arepas | columns | str collect ";"
+        "#)
+    );
+
+    assert_eq!(actual.out, "color");
+}
+
+#[test]
+fn multiple_columns_by_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(r#"
+            echo [
+              [arepas, color, price];
+              [3,  white, 1]
+              [8, yellow, 2]
+              [4,  white, 3]
+            ] | drop column arepas price | columns | str collect ";"
+        "#)
+    );
+
+    assert_eq!(actual.out, "color");
+}
+
 #[test]
 fn rows() {
     let actual = nu!(
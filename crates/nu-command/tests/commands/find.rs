@@ -62,6 +62,45 @@ fn find_with_string_search_with_string_not_found() {
     assert_eq!(actual.out, "");
 }
 
+#[test]
+fn find_with_term_search_only_in_given_columns() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+    r#"
+        [[name rank]; [moe 1] [larry 2] [curly 3]] | find 2 --columns [rank] | get name.0
+    "#
+    ));
+
+    assert_eq!(actual.out, "larry");
+}
+
+#[test]
+fn find_with_term_does_not_search_excluded_columns() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+    r#"
+        [[name rank]; [moe 1] [larry 2] [curly 3]] | find larry --columns [rank] | length
+    "#
+    ));
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn find_with_regex_search_only_in_given_columns() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+    r#"
+        [[name rank]; [moe 1] [larry 2] [curly 3]] | find --regex "l" --columns [name] | to json -r
+    "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"larry","rank":2},{"name":"curly","rank":3}]"#
+    );
+}
+
 #[test]
 fn find_with_filepath_search_with_string() {
     Playground::setup("filepath_test_1", |dirs, sandbox| {
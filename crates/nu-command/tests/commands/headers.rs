@@ -15,6 +15,28 @@ fn headers_uses_first_row_as_header() {
     assert_eq!(actual.out, r#"["r1c0","r2c0"]"#)
 }
 
+#[test]
+fn headers_trims_whitespace() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+        r#"
+            echo "a , b |1,2" | split row "|" | split column "," | headers -t | columns | str collect ";""#
+    ));
+
+    assert_eq!(actual.out, "a;b");
+}
+
+#[test]
+fn headers_dedupes_repeated_names() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+        r#"
+            echo "a,a,a|1,2,3" | split row "|" | split column "," | headers -d | columns | str collect ";""#
+    ));
+
+    assert_eq!(actual.out, "a;a1;a2");
+}
+
 #[test]
 fn headers_adds_missing_column_name() {
     let actual = nu!(
@@ -15,8 +15,6 @@ fn help_commands_length() {
     assert!(is_positive);
 }
 
-// FIXME: jt: needs more work
-#[ignore]
 #[test]
 fn help_generate_docs_length() {
     let actual = nu!(
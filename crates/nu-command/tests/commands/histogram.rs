@@ -2,8 +2,6 @@ use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
-// FIXME: jt: needs more work
-#[ignore]
 #[test]
 fn summarizes_by_column_given() {
     Playground::setup("histogram_test_1", |dirs, sandbox| {
@@ -35,8 +33,6 @@ fn summarizes_by_column_given() {
     })
 }
 
-// FIXME: jt: needs more work
-#[ignore]
 #[test]
 fn summarizes_by_values() {
     Playground::setup("histogram_test_2", |dirs, sandbox| {
@@ -65,8 +61,6 @@ fn summarizes_by_values() {
     })
 }
 
-// FIXME: jt: needs more work
-#[ignore]
 #[test]
 fn help() {
     Playground::setup("histogram_test_3", |dirs, _sandbox| {
@@ -96,8 +90,6 @@ fn help() {
     })
 }
 
-// FIXME: jt: needs more work
-#[ignore]
 #[test]
 fn count() {
     let actual = nu!(
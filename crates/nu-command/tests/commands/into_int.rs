@@ -35,3 +35,27 @@ fn into_int_int() {
 
     assert!(actual.out.contains('1'));
 }
+
+#[test]
+fn into_int_binary() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        0x[0102] | into int
+        "#
+    ));
+
+    assert!(actual.out.contains("513"));
+}
+
+#[test]
+fn into_int_binary_too_large() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        0x[010203040506070809] | into int
+        "#
+    ));
+
+    assert!(actual.err.contains("too large"));
+}
@@ -0,0 +1,41 @@
+use nu_test_support::nu;
+
+#[test]
+fn load_env_from_argument_sets_variables() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "load-env {SHELL: nu, EDITOR: cat}; echo $env.SHELL $env.EDITOR | str collect '-'"
+    );
+
+    assert_eq!(actual.out, "nu-cat");
+}
+
+#[test]
+fn load_env_from_pipeline_sets_variables() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo '{\"VFOO\": \"bar\", \"VBAZ\": \"quux\"}' | from json | load-env; echo $env.VFOO $env.VBAZ | str collect '-'"
+    );
+
+    assert_eq!(actual.out, "bar-quux");
+}
+
+#[test]
+fn load_env_overwrites_existing_variable() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "with-env [VFOO old] { load-env {VFOO: new}; echo $env.VFOO }"
+    );
+
+    assert_eq!(actual.out, "new");
+}
+
+#[test]
+fn load_env_rejects_non_record_input() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [1 2 3] | load-env"
+    );
+
+    assert!(!actual.err.is_empty());
+}
@@ -8,6 +8,7 @@ mod compact;
 mod cp;
 mod def;
 mod default;
+mod detect_columns;
 mod drop;
 mod each;
 mod echo;
@@ -30,6 +31,7 @@ mod into_int;
 mod last;
 mod length;
 mod lines;
+mod load_env;
 mod ls;
 mod math;
 mod merge;
@@ -52,6 +54,7 @@ mod run_external;
 mod save;
 mod select;
 mod semicolon;
+mod seq;
 mod skip;
 mod sort_by;
 mod source;
@@ -107,6 +107,30 @@ fn moves_a_column_after() {
     })
 }
 
+#[test]
+fn errors_when_moving_a_column_relative_to_itself() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[a b]; [1 2]] | move a --before a
+        "#
+    ));
+
+    assert!(actual.err.contains("relative to itself"));
+}
+
+#[test]
+fn errors_when_no_columns_are_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[a b]; [1 2]] | move --before b
+        "#
+    ));
+
+    assert!(actual.err.contains("no columns"));
+}
+
 #[test]
 fn moves_columns_after() {
     Playground::setup("move_column_test_4", |dirs, sandbox| {
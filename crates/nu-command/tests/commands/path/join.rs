@@ -31,6 +31,20 @@ fn returns_path_joined_from_list() {
     assert_eq!(actual.out, expected);
 }
 
+#[test]
+fn returns_path_joined_with_multiple_parts() {
+    let actual = nu!(
+        cwd: "tests", pipeline(
+        r#"
+            echo home
+            | path join viking spam.txt
+        "#
+    ));
+
+    let expected = join_path_sep(&["home", "viking", "spam.txt"]);
+    assert_eq!(actual.out, expected);
+}
+
 #[test]
 fn drop_one_path_join() {
     let actual = nu!(
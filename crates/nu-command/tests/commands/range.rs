@@ -66,3 +66,45 @@ fn negative_indices() {
         assert_eq!(actual.out, "1");
     });
 }
+
+#[test]
+fn binary_slice() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            0x[de ad be ef]
+            | range 1..2
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "0x[ADBE]");
+}
+
+#[test]
+fn binary_slice_open_ended() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            0x[de ad be ef]
+            | range 2..
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "0x[BEEF]");
+}
+
+#[test]
+fn binary_slice_negative_indices() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            0x[de ad be ef]
+            | range (-2)..
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "0x[BEEF]");
+}
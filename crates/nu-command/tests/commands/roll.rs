@@ -30,6 +30,17 @@ mod rows {
         assert_eq!(actual.out, "HERE");
     }
 
+    #[test]
+    fn rolling_an_empty_list_is_a_noop() {
+        let actual = nu!(
+        cwd: ".",
+        pipeline(r#"
+            [] | roll down | length
+        "#));
+
+        assert_eq!(actual.out, "0");
+    }
+
     #[test]
     fn can_roll_up() {
         let actual = nu!(
@@ -81,3 +81,51 @@ fn save_append_will_not_overwrite_content() {
         assert_eq!(actual, "hello world");
     })
 }
+
+#[test]
+fn save_atomic_writes_and_cleans_up_temp_file() {
+    Playground::setup("save_test_5", |dirs, sandbox| {
+        sandbox.with_files(vec![]);
+
+        let expected_file = dirs.test().join("atomic.txt");
+
+        nu!(
+            cwd: dirs.root(),
+            r#"echo hello | save --raw --atomic save_test_5/atomic.txt"#,
+        );
+
+        let actual = file_contents(&expected_file);
+        assert_eq!(actual, "hello");
+
+        let leftovers = std::fs::read_dir(dirs.test())
+            .expect("Failed to read test directory")
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().contains(".tmp-"))
+            .count();
+        assert_eq!(leftovers, 0);
+    })
+}
+
+#[test]
+fn save_atomic_append_keeps_existing_content() {
+    Playground::setup("save_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![]);
+
+        let expected_file = dirs.test().join("atomic.txt");
+
+        {
+            let mut file =
+                std::fs::File::create(&expected_file).expect("Failed to create test file");
+            file.write_all("hello ".as_bytes())
+                .expect("Failed to write to test file")
+        }
+
+        nu!(
+            cwd: dirs.root(),
+            r#"echo world | save --append --atomic save_test_6/atomic.txt"#,
+        );
+
+        let actual = file_contents(expected_file);
+        assert_eq!(actual, "hello world");
+    })
+}
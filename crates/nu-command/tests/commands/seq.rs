@@ -0,0 +1,37 @@
+use nu_test_support::nu;
+
+#[test]
+fn float_bounds_and_step() {
+    let actual = nu!(cwd: ".", "seq 1.0 0.25 2.0 | length");
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn exponent_notation_bounds() {
+    let actual = nu!(cwd: ".", "seq 1 1e3 | length");
+
+    assert_eq!(actual.out, "1000");
+}
+
+#[test]
+fn huge_sequence_is_lazy() {
+    // would take forever (and gigabytes) if seq collected its output up front
+    let actual = nu!(cwd: ".", "seq 1 1000000000 | first 3 | math sum");
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn countdown() {
+    let actual = nu!(cwd: ".", "seq 3 -1 1 | to json --raw");
+
+    assert_eq!(actual.out, "[3,2,1]");
+}
+
+#[test]
+fn all_integers_stay_integers() {
+    let actual = nu!(cwd: ".", "seq 1 3 | to json --raw");
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
@@ -49,3 +49,19 @@ fn condition_is_met() {
         assert_eq!(actual.out, "4");
     })
 }
+
+#[test]
+fn does_not_consume_the_whole_stream() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            nu --testbin iecho yes
+            | lines
+            | take while $it == "yes"
+            | first 3
+            | length
+            "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
@@ -10,6 +10,26 @@ fn with_env_extends_environment() {
     assert_eq!(actual.out, "BARRRR");
 }
 
+#[test]
+fn with_env_record_extends_environment() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "with-env {FOO: BARRRR} {echo $env} | get FOO"
+    );
+
+    assert_eq!(actual.out, "BARRRR");
+}
+
+#[test]
+fn with_env_errors_on_unpaired_variable() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "with-env [FOO BARRRR BAZ] {echo $env.FOO}"
+    );
+
+    assert!(!actual.err.is_empty());
+}
+
 #[test]
 fn with_env_shorthand() {
     let actual = nu!(
@@ -207,3 +207,28 @@ fn from_csv_text_skipping_headers_to_table() {
         assert_eq!(actual.out, "3");
     })
 }
+
+#[test]
+fn from_csv_malformed_text_reports_the_position() {
+    Playground::setup("filter_from_csv_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "malformed.txt",
+            r#"
+                first,second
+                1,2
+                3,4,5
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open malformed.txt
+                | from csv
+            "#
+        ));
+
+        assert!(actual.err.contains("error parsing delimited text"));
+        assert!(actual.err.contains("line 3"));
+    })
+}
@@ -1,7 +1,7 @@
 use nu_protocol::{
-    ast::Call,
+    ast::{Call, Expr},
     engine::{EngineState, Stack},
-    FromValue, ShellError,
+    FromValue, ShellError, Value,
 };
 
 use crate::eval_expression;
@@ -60,8 +60,19 @@ impl CallExt for Call {
         let mut output = vec![];
 
         for expr in self.positional_iter().skip(starting_pos) {
-            let result = eval_expression(engine_state, stack, expr)?;
-            output.push(FromValue::from_value(&result)?);
+            if let Expr::Spread(inner) = &expr.expr {
+                match eval_expression(engine_state, stack, inner)? {
+                    Value::List { vals, .. } => {
+                        for val in vals {
+                            output.push(FromValue::from_value(&val)?);
+                        }
+                    }
+                    _ => return Err(ShellError::CannotSpreadAsList(inner.span)),
+                }
+            } else {
+                let result = eval_expression(engine_state, stack, expr)?;
+                output.push(FromValue::from_value(&result)?);
+            }
         }
 
         Ok(output)
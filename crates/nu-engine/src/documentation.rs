@@ -2,7 +2,7 @@ use itertools::Itertools;
 use nu_protocol::{
     ast::Call,
     engine::{EngineState, Stack},
-    Example, IntoPipelineData, Signature, Span, Value,
+    Example, IntoPipelineData, Signature, Span, Type, Value,
 };
 
 use std::borrow::Borrow;
@@ -135,8 +135,9 @@ pub fn generate_docs(engine_state: &EngineState, stack: &mut Stack, head: Span)
 
 fn retrieve_doc_link(name: &str) -> Option<String> {
     let doc_name = name.split_whitespace().join("_"); // Because .replace(" ", "_") didn't work
-    let mut entries =
-        std::fs::read_dir(COMMANDS_DOCS_DIR).expect("Directory for command docs are missing!");
+                                                      // The docs directory only exists in a checkout of the repository, so
+                                                      // there is simply no link to offer when it is not around
+    let mut entries = std::fs::read_dir(COMMANDS_DOCS_DIR).ok()?;
     entries.find_map(|r| {
         r.map_or(None, |de| {
             if de.file_name().to_string_lossy() == format!("{}.{}", &doc_name, "md") {
@@ -188,6 +189,13 @@ pub fn get_documentation(
         ));
     }
 
+    if sig.input_type != Type::Any || sig.output_type != Type::Any {
+        long_desc.push_str(&format!(
+            "Input/output type: {} -> {}\n\n",
+            sig.input_type, sig.output_type
+        ));
+    }
+
     long_desc.push_str(&format!("Usage:\n  > {}\n", sig.call_signature()));
 
     if !subcommands.is_empty() {
@@ -210,9 +218,19 @@ pub fn get_documentation(
             long_desc.push_str(&format!("  {}: {}\n", positional.name, positional.desc));
         }
         for positional in &sig.optional_positional {
+            // Show the source of the default value so the caller knows what
+            // they get when the parameter is omitted
+            let default = if let Some(value) = &positional.default_value {
+                format!(
+                    ", default: {}",
+                    String::from_utf8_lossy(engine_state.get_span_contents(&value.span))
+                )
+            } else {
+                String::new()
+            };
             long_desc.push_str(&format!(
-                "  (optional) {}: {}\n",
-                positional.name, positional.desc
+                "  (optional{}) {}: {}\n",
+                default, positional.name, positional.desc
             ));
         }
 
@@ -87,7 +87,7 @@ pub fn env_to_string(
         ConversionResult::CellPathError => match value.as_string() {
             Ok(s) => Ok(s),
             Err(_) => {
-                if env_name == ENV_PATH_NAME {
+                if is_path_name(env_name) {
                     // Try to convert PATH/Path list to a string
                     match value {
                         Value::List { vals, .. } => {
@@ -201,6 +201,20 @@ pub fn path_str(
     env_to_string(pathname, &pathval, engine_state, stack)
 }
 
+/// Whether the name refers to the path environment variable
+///
+/// On Windows, both "Path" and "PATH" are in play since externals can set either spelling.
+fn is_path_name(name: &str) -> bool {
+    #[cfg(windows)]
+    {
+        name == ENV_PATH_NAME || name == ENV_PATH_NAME_SECONDARY
+    }
+    #[cfg(not(windows))]
+    {
+        name == ENV_PATH_NAME
+    }
+}
+
 fn get_converted_value(
     engine_state: &EngineState,
     stack: &Stack,
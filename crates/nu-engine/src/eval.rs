@@ -7,11 +7,22 @@ use nu_protocol::ast::{Block, Call, Expr, Expression, Operator};
 use nu_protocol::engine::{EngineState, Stack, Visibility};
 use nu_protocol::{
     IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, Range, ShellError, Span,
-    Spanned, Unit, Value, VarId, ENV_VARIABLE_ID,
+    Spanned, Type, Unit, Value, VarId, ENV_VARIABLE_ID,
 };
 
 use crate::{current_dir_str, get_full_help};
 
+/// The variable a binary assignment writes to, if its left-hand side is one
+fn assignment_target(expr: &Expression) -> Option<VarId> {
+    match &expr.expr {
+        Expr::Var(var_id) => Some(*var_id),
+        Expr::FullCellPath(cell_path) if cell_path.tail.is_empty() => {
+            assignment_target(&cell_path.head)
+        }
+        _ => None,
+    }
+}
+
 pub fn eval_operator(op: &Expression) -> Result<Operator, ShellError> {
     match op {
         Expression {
@@ -44,6 +55,16 @@ pub fn eval_call(
         }
         .into_pipeline_data())
     } else if let Some(block_id) = decl.get_block_id() {
+        let recursion_limit = caller_stack.get_config()?.recursion_limit;
+        caller_stack.recursion_count += 1;
+        if caller_stack.recursion_count > recursion_limit {
+            caller_stack.recursion_count = 0;
+            return Err(ShellError::RecursionLimitReached(
+                recursion_limit,
+                call.head,
+            ));
+        }
+
         let block = engine_state.get_block(block_id);
 
         let mut callee_stack = caller_stack.gather_captures(&block.captures);
@@ -61,6 +82,15 @@ pub fn eval_call(
 
             if let Some(arg) = call.positional_nth(param_idx) {
                 let result = eval_expression(engine_state, caller_stack, arg)?;
+                let expected = param.shape.to_type();
+                if !type_compatible(&expected, &result.get_type()) {
+                    return Err(ShellError::ParameterTypeMismatch(
+                        param.name.clone(),
+                        expected.to_string(),
+                        result.get_type().to_string(),
+                        arg.span,
+                    ));
+                }
                 callee_stack.add_var(var_id, result);
             } else if let Some(arg) = &param.default_value {
                 let result = eval_expression(engine_state, caller_stack, arg)?;
@@ -77,8 +107,15 @@ pub fn eval_call(
                 decl.signature().required_positional.len()
                     + decl.signature().optional_positional.len(),
             ) {
-                let result = eval_expression(engine_state, caller_stack, arg)?;
-                rest_items.push(result);
+                if let Expr::Spread(inner) = &arg.expr {
+                    match eval_expression(engine_state, caller_stack, inner)? {
+                        Value::List { vals, .. } => rest_items.extend(vals),
+                        _ => return Err(ShellError::CannotSpreadAsList(inner.span)),
+                    }
+                } else {
+                    let result = eval_expression(engine_state, caller_stack, arg)?;
+                    rest_items.push(result);
+                }
             }
 
             let span = if let Some(rest_item) = rest_items.first() {
@@ -107,6 +144,16 @@ pub fn eval_call(
                             let result = eval_expression(engine_state, caller_stack, arg)?;
 
                             callee_stack.add_var(var_id, result);
+                        } else if named.arg.is_none() {
+                            // A switch given at the call site is true, even if
+                            // its default value says otherwise
+                            callee_stack.add_var(
+                                var_id,
+                                Value::Bool {
+                                    val: true,
+                                    span: call.head,
+                                },
+                            )
                         } else if let Some(arg) = &named.default_value {
                             let result = eval_expression(engine_state, caller_stack, arg)?;
 
@@ -125,7 +172,11 @@ pub fn eval_call(
                 }
 
                 if !found {
-                    if named.arg.is_none() {
+                    if let Some(arg) = &named.default_value {
+                        let result = eval_expression(engine_state, caller_stack, arg)?;
+
+                        callee_stack.add_var(var_id, result);
+                    } else if named.arg.is_none() {
                         callee_stack.add_var(
                             var_id,
                             Value::Bool {
@@ -133,10 +184,6 @@ pub fn eval_call(
                                 span: call.head,
                             },
                         )
-                    } else if let Some(arg) = &named.default_value {
-                        let result = eval_expression(engine_state, caller_stack, arg)?;
-
-                        callee_stack.add_var(var_id, result);
                     } else {
                         callee_stack.add_var(var_id, Value::Nothing { span: call.head })
                     }
@@ -153,6 +200,14 @@ pub fn eval_call(
             call.redirect_stderr,
         );
 
+        caller_stack.recursion_count -= 1;
+
+        // `return` unwinds to the nearest custom command boundary
+        let result = match result {
+            Err(ShellError::Return(_, value)) => Ok((*value).into_pipeline_data()),
+            other => other,
+        };
+
         if block.redirect_env {
             let caller_env_vars = caller_stack.get_env_var_names(engine_state);
 
@@ -181,6 +236,20 @@ pub fn eval_call(
     }
 }
 
+/// Check an argument's runtime type against a parameter's declared type. A
+/// declared type of `any` accepts everything, and types the signature can only
+/// express loosely (bare `list`, `record`) are not checked more deeply.
+fn type_compatible(expected: &Type, found: &Type) -> bool {
+    match (expected, found) {
+        (Type::Any, _) | (_, Type::Any) => true,
+        (Type::Number, ty) => ty.is_numeric(),
+        (Type::List(expected), Type::List(found)) => type_compatible(expected, found),
+        (Type::List(inner), Type::Table(_)) if **inner == Type::Any => true,
+        (Type::Record(inner), Type::Record(_) | Type::Table(_)) if inner.is_empty() => true,
+        _ => expected == found,
+    }
+}
+
 fn eval_external(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -333,9 +402,47 @@ pub fn eval_expression(
         }
         Expr::BinaryOp(lhs, op, rhs) => {
             let op_span = op.span;
-            let lhs = eval_expression(engine_state, stack, lhs)?;
             let op = eval_operator(op)?;
 
+            if let Operator::Assign
+            | Operator::PlusAssign
+            | Operator::MinusAssign
+            | Operator::MultiplyAssign
+            | Operator::DivideAssign = op
+            {
+                let rhs = eval_expression(engine_state, stack, rhs)?;
+
+                let var_id = match assignment_target(lhs) {
+                    Some(var_id) => var_id,
+                    None => {
+                        return Err(ShellError::SpannedLabeledError(
+                            "Assignment requires a variable".into(),
+                            "needs to be a variable".into(),
+                            lhs.span,
+                        ))
+                    }
+                };
+
+                let new_value =
+                    match op {
+                        Operator::PlusAssign => eval_expression(engine_state, stack, lhs)?
+                            .add(op_span, &rhs, expr.span)?,
+                        Operator::MinusAssign => eval_expression(engine_state, stack, lhs)?
+                            .sub(op_span, &rhs, expr.span)?,
+                        Operator::MultiplyAssign => eval_expression(engine_state, stack, lhs)?
+                            .mul(op_span, &rhs, expr.span)?,
+                        Operator::DivideAssign => eval_expression(engine_state, stack, lhs)?
+                            .div(op_span, &rhs, expr.span)?,
+                        _ => rhs,
+                    };
+
+                stack.add_var(var_id, new_value);
+
+                return Ok(Value::Nothing { span: expr.span });
+            }
+
+            let lhs = eval_expression(engine_state, stack, lhs)?;
+
             match op {
                 Operator::And => {
                     if !lhs.is_true() {
@@ -427,6 +534,12 @@ pub fn eval_expression(
                     let rhs = eval_expression(engine_state, stack, rhs)?;
                     lhs.starts_with(op_span, &rhs, expr.span)
                 }
+                // the assignment operators are all handled above
+                Operator::Assign
+                | Operator::PlusAssign
+                | Operator::MinusAssign
+                | Operator::MultiplyAssign
+                | Operator::DivideAssign => Err(ShellError::UnsupportedOperator(op, op_span)),
             }
         }
         Expr::Subexpression(block_id) => {
@@ -454,7 +567,14 @@ pub fn eval_expression(
         Expr::List(x) => {
             let mut output = vec![];
             for expr in x {
-                output.push(eval_expression(engine_state, stack, expr)?);
+                if let Expr::Spread(inner) = &expr.expr {
+                    match eval_expression(engine_state, stack, inner)? {
+                        Value::List { vals, .. } => output.extend(vals),
+                        _ => return Err(ShellError::CannotSpreadAsList(inner.span)),
+                    }
+                } else {
+                    output.push(eval_expression(engine_state, stack, expr)?);
+                }
             }
             Ok(Value::List {
                 vals: output,
@@ -465,8 +585,22 @@ pub fn eval_expression(
             let mut cols = vec![];
             let mut vals = vec![];
             for (col, val) in fields {
-                cols.push(eval_expression(engine_state, stack, col)?.as_string()?);
-                vals.push(eval_expression(engine_state, stack, val)?);
+                if let Expr::Spread(inner) = &col.expr {
+                    match eval_expression(engine_state, stack, inner)? {
+                        Value::Record {
+                            cols: inner_cols,
+                            vals: inner_vals,
+                            ..
+                        } => {
+                            cols.extend(inner_cols);
+                            vals.extend(inner_vals);
+                        }
+                        _ => return Err(ShellError::CannotSpreadAsRecord(inner.span)),
+                    }
+                } else {
+                    cols.push(eval_expression(engine_state, stack, col)?.as_string()?);
+                    vals.push(eval_expression(engine_state, stack, val)?);
+                }
             }
 
             Ok(Value::Record {
@@ -539,6 +673,8 @@ pub fn eval_expression(
             })
         }
         Expr::Signature(_) => Ok(Value::Nothing { span: expr.span }),
+        Expr::MatchBlock(_) => Ok(Value::Nothing { span: expr.span }), // match blocks are handled by `match`
+        Expr::Spread(_) => Err(ShellError::CannotSpreadAsList(expr.span)),
         Expr::Garbage => Ok(Value::Nothing { span: expr.span }),
         Expr::Nothing => Ok(Value::Nothing { span: expr.span }),
     }
@@ -1154,7 +1290,15 @@ pub fn eval_variable(
 
             if let Some(mut config_path) = nu_path::config_dir() {
                 config_path.push("nushell");
+
+                output_cols.push("default-config-dir".into());
+                output_vals.push(Value::String {
+                    val: config_path.to_string_lossy().to_string(),
+                    span,
+                });
+
                 let mut env_config_path = config_path.clone();
+                let mut loginshell_path = config_path.clone();
 
                 let mut history_path = config_path.clone();
 
@@ -1181,6 +1325,14 @@ pub fn eval_variable(
                     val: env_config_path.to_string_lossy().to_string(),
                     span,
                 });
+
+                loginshell_path.push("login.nu");
+
+                output_cols.push("loginshell-path".into());
+                output_vals.push(Value::String {
+                    val: loginshell_path.to_string_lossy().to_string(),
+                    span,
+                });
             }
 
             #[cfg(feature = "plugin")]
@@ -1216,10 +1368,39 @@ pub fn eval_variable(
                 })
             }
 
+            output_cols.push("is-interactive".into());
+            output_vals.push(Value::Bool {
+                val: engine_state.is_interactive,
+                span,
+            });
+
+            output_cols.push("is-login".into());
+            output_vals.push(Value::Bool {
+                val: engine_state.is_login,
+                span,
+            });
+
             let pid = std::process::id();
             output_cols.push("pid".into());
             output_vals.push(Value::int(pid as i64, span));
 
+            output_cols.push("os-info".into());
+            output_vals.push(Value::Record {
+                cols: vec![
+                    "name".into(),
+                    "arch".into(),
+                    "family".into(),
+                    "kernel_version".into(),
+                ],
+                vals: vec![
+                    Value::string(std::env::consts::OS, span),
+                    Value::string(std::env::consts::ARCH, span),
+                    Value::string(std::env::consts::FAMILY, span),
+                    kernel_version(span),
+                ],
+                span,
+            });
+
             Ok(Value::Record {
                 cols: output_cols,
                 vals: output_vals,
@@ -1250,6 +1431,15 @@ pub fn eval_variable(
     }
 }
 
+fn kernel_version(span: Span) -> Value {
+    #[cfg(target_os = "linux")]
+    if let Ok(version) = std::fs::read_to_string("/proc/sys/kernel/osrelease") {
+        return Value::string(version.trim(), span);
+    }
+
+    Value::string("unknown", span)
+}
+
 fn compute(size: i64, unit: Unit, span: Span) -> Value {
     match unit {
         Unit::Byte => Value::Filesize { val: size, span },
@@ -10,7 +10,7 @@ pub use column::get_columns;
 pub use documentation::{generate_docs, get_brief_help, get_documentation, get_full_help};
 pub use env::*;
 pub use eval::{
-    eval_block, eval_call, eval_expression, eval_expression_with_input, eval_operator,
-    eval_subexpression,
+    create_scope, eval_block, eval_call, eval_expression, eval_expression_with_input,
+    eval_operator, eval_subexpression,
 };
 pub use glob_from::glob_from;
@@ -90,6 +90,22 @@ pub enum ParseError {
     #[diagnostic(code(nu::parser::variable_not_found), url(docsrs))]
     VariableNotFound(#[label = "variable not found"] Span),
 
+    #[error("Command does not support {1} input.")]
+    #[diagnostic(code(nu::parser::input_type_mismatch), url(docsrs))]
+    InputMismatch(
+        String,
+        String,
+        #[label("command expects {0} input, not {1}")] Span,
+    ),
+
+    #[error("Not a constant.")]
+    #[diagnostic(
+        code(nu::parser::not_a_constant),
+        url(docsrs),
+        help("only a subset of expressions can be evaluated at parse time")
+    )]
+    NotAConstant(#[label = "not a constant value"] Span),
+
     #[error("Variable name not supported.")]
     #[diagnostic(code(nu::parser::variable_not_valid), url(docsrs))]
     VariableNotValid(#[label = "variable name can't contain spaces or quotes"] Span),
@@ -213,6 +229,18 @@ pub enum ParseError {
     #[diagnostic(code(nu::parser::assignment_mismatch), url(docsrs))]
     AssignmentMismatch(String, String, #[label("{1}")] Span),
 
+    #[error("Assignment operations require a variable.")]
+    #[diagnostic(code(nu::parser::assignment_requires_variable), url(docsrs))]
+    AssignmentRequiresVar(#[label("needs to be a variable")] Span),
+
+    #[error("Assignment to an immutable variable.")]
+    #[diagnostic(
+        code(nu::parser::assignment_requires_mutable_variable),
+        url(docsrs),
+        help("declare the variable with `mut`, instead of `let`")
+    )]
+    AssignmentRequiresMutableVar(#[label("needs to be a mutable variable")] Span),
+
     #[error("Missing import pattern.")]
     #[diagnostic(code(nu::parser::missing_import_pattern), url(docsrs))]
     MissingImportPattern(#[label = "needs an import pattern"] Span),
@@ -266,6 +294,8 @@ impl ParseError {
             ParseError::IncorrectValue(_, s, _) => *s,
             ParseError::MultipleRestParams(s) => *s,
             ParseError::VariableNotFound(s) => *s,
+            ParseError::NotAConstant(s) => *s,
+            ParseError::InputMismatch(_, _, s) => *s,
             ParseError::VariableNotValid(s) => *s,
             ParseError::ModuleNotFound(s) => *s,
             ParseError::NotFound(s) => *s,
@@ -291,6 +321,8 @@ impl ParseError {
             ParseError::ExtraColumns(_, s) => *s,
             ParseError::MissingColumns(_, s) => *s,
             ParseError::AssignmentMismatch(_, _, s) => *s,
+            ParseError::AssignmentRequiresVar(s) => *s,
+            ParseError::AssignmentRequiresMutableVar(s) => *s,
             ParseError::MissingImportPattern(s) => *s,
             ParseError::WrongImportPattern(s) => *s,
             ParseError::ExportNotFound(s) => *s,
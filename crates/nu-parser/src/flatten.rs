@@ -16,6 +16,7 @@ pub enum FlatShape {
     External,
     ExternalArg,
     Literal,
+    MatchPattern,
     Operator,
     Signature,
     String,
@@ -46,6 +47,7 @@ impl Display for FlatShape {
             FlatShape::External => write!(f, "shape_external"),
             FlatShape::ExternalArg => write!(f, "shape_externalarg"),
             FlatShape::Literal => write!(f, "shape_literal"),
+            FlatShape::MatchPattern => write!(f, "shape_match_pattern"),
             FlatShape::Operator => write!(f, "shape_operator"),
             FlatShape::Signature => write!(f, "shape_signature"),
             FlatShape::String => write!(f, "shape_string"),
@@ -320,6 +322,19 @@ pub fn flatten_expression(
             }
             output
         }
+        Expr::MatchBlock(matches) => {
+            let mut output = vec![];
+
+            for (pattern, expr) in matches {
+                output.push((pattern.span, FlatShape::MatchPattern));
+                if let Some(guard) = &pattern.guard {
+                    output.extend(flatten_expression(working_set, guard));
+                }
+                output.extend(flatten_expression(working_set, expr));
+            }
+
+            output
+        }
         Expr::StringInterpolation(exprs) => {
             let mut output = vec![(
                 Span {
@@ -405,6 +420,17 @@ pub fn flatten_expression(
         Expr::Signature(_) => {
             vec![(expr.span, FlatShape::Signature)]
         }
+        Expr::Spread(inner_expr) => {
+            let mut output = vec![(
+                Span {
+                    start: expr.span.start,
+                    end: expr.span.start + 3,
+                },
+                FlatShape::Operator,
+            )];
+            output.extend(flatten_expression(working_set, inner_expr));
+            output
+        }
         Expr::String(_) => {
             vec![(expr.span, FlatShape::String)]
         }
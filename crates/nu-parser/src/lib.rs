@@ -4,6 +4,7 @@ mod known_external;
 mod lex;
 mod lite_parse;
 mod parse_keywords;
+mod parse_patterns;
 mod parser;
 mod type_check;
 
@@ -18,5 +19,7 @@ pub use parser::{
     trim_quotes, Import,
 };
 
+pub use parse_keywords::parse_module_block;
+
 #[cfg(feature = "plugin")]
 pub use parse_keywords::parse_register;
@@ -5,7 +5,8 @@ use nu_protocol::{
         ImportPatternMember, Pipeline,
     },
     engine::StateWorkingSet,
-    span, Exportable, Overlay, PositionalArg, Span, SyntaxShape, Type, CONFIG_VARIABLE_ID,
+    span, AliasId, DeclId, Exportable, Overlay, PositionalArg, Span, SyntaxShape, Type, Value,
+    CONFIG_VARIABLE_ID,
 };
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
@@ -357,10 +358,21 @@ pub fn parse_def(
         (&name_expr.as_string(), sig.as_signature(), block.as_block())
     {
         if let Some(decl_id) = working_set.find_predecl(name.as_bytes()) {
+            // Infer the command's output type from the final expression of the
+            // body so that pipelines calling it can be shape-checked as well
+            let output_type = working_set
+                .get_block(block_id)
+                .pipelines
+                .last()
+                .and_then(|pipeline| pipeline.expressions.last())
+                .map(|expr| expr.ty.clone())
+                .unwrap_or(Type::Any);
+
             let declaration = working_set.get_decl_mut(decl_id);
 
             signature.name = name.clone();
             signature.usage = usage;
+            signature.output_type = output_type;
 
             *declaration = signature.clone().into_block_command(block_id);
 
@@ -521,10 +533,18 @@ pub fn parse_alias(
         }
 
         if let Some(decl_id) = working_set.find_decl(b"alias") {
+            // The replacement may be a whole pipeline; only the part before
+            // the first pipe fits the `alias` signature, the rest is kept
+            // verbatim in the replacement spans below.
+            let pipe = spans
+                .iter()
+                .position(|s| working_set.get_span_contents(*s) == b"|")
+                .unwrap_or(spans.len());
+
             let (call, _) = parse_internal_call(
                 working_set,
                 spans[0],
-                &spans[1..],
+                &spans[1..pipe],
                 decl_id,
                 expand_aliases_denylist,
             );
@@ -872,6 +892,66 @@ pub fn parse_export(
                     None
                 }
             }
+            b"module" => {
+                let lite_command = LiteCommand {
+                    comments: lite_command.comments.clone(),
+                    parts: spans[1..].to_vec(),
+                };
+                let (pipeline, err) =
+                    parse_module(working_set, &lite_command.parts, expand_aliases_denylist);
+                error = error.or(err);
+
+                let export_module_decl_id =
+                    if let Some(id) = working_set.find_decl(b"export module") {
+                        id
+                    } else {
+                        return (
+                            garbage_pipeline(spans),
+                            None,
+                            Some(ParseError::InternalError(
+                                "missing 'export module' command".into(),
+                                export_span,
+                            )),
+                        );
+                    };
+
+                // Trying to warp the 'module' call into the 'export module' in a very clumsy way
+                if let Some(Expression {
+                    expr: Expr::Call(ref module_call),
+                    ..
+                }) = pipeline.expressions.get(0)
+                {
+                    call = module_call.clone();
+
+                    call.head = span(&spans[0..=1]);
+                    call.decl_id = export_module_decl_id;
+                } else {
+                    error = error.or_else(|| {
+                        Some(ParseError::InternalError(
+                            "unexpected output from parsing a module".into(),
+                            span(&spans[1..]),
+                        ))
+                    });
+                };
+
+                if error.is_none() {
+                    let module_name = working_set.get_span_contents(spans[2]);
+                    let module_name = trim_quotes(module_name);
+                    if let Some(overlay_id) = working_set.find_overlay(module_name) {
+                        Some(Exportable::Overlay(overlay_id))
+                    } else {
+                        error = error.or_else(|| {
+                            Some(ParseError::InternalError(
+                                "failed to find added module".into(),
+                                span(&spans[1..]),
+                            ))
+                        });
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
             b"env" => {
                 if let Some(id) = working_set.find_decl(b"export env") {
                     call.decl_id = id;
@@ -961,7 +1041,7 @@ pub fn parse_export(
                 error = error.or_else(|| {
                     Some(ParseError::Expected(
                         // TODO: Fill in more keywords as they come
-                        "def, def-env, alias, or env keyword".into(),
+                        "def, def-env, alias, module, or env keyword".into(),
                         spans[1],
                     ))
                 });
@@ -972,12 +1052,12 @@ pub fn parse_export(
     } else {
         error = error.or_else(|| {
             Some(ParseError::MissingPositional(
-                "def, def-env, alias, or env keyword".into(), // TODO: keep filling more keywords as they come
+                "def, def-env, alias, module, or env keyword".into(), // TODO: keep filling more keywords as they come
                 Span {
                     start: export_span.end,
                     end: export_span.end,
                 },
-                "'def', `def-env`, `alias`, or 'env' keyword.".to_string(),
+                "'def', `def-env`, `alias`, `module`, or 'env' keyword.".to_string(),
             ))
         });
 
@@ -1058,6 +1138,15 @@ pub fn parse_module_block(
 
                         (pipeline, err)
                     }
+                    b"module" => {
+                        let (pipeline, err) = parse_module(
+                            working_set,
+                            &pipeline.commands[0].parts,
+                            expand_aliases_denylist,
+                        );
+
+                        (pipeline, err)
+                    }
                     // TODO: Currently, it is not possible to define a private env var.
                     // TODO: Exported env vars are usable iside the module only if correctly
                     // exported by the user. For example:
@@ -1088,6 +1177,9 @@ pub fn parse_module_block(
                                 Some(Exportable::Alias(alias_id)) => {
                                     overlay.add_alias(name, alias_id);
                                 }
+                                Some(Exportable::Overlay(overlay_id)) => {
+                                    overlay.add_overlay(name, overlay_id);
+                                }
                                 None => {} // None should always come with error from parse_export()
                             }
                         }
@@ -1097,7 +1189,7 @@ pub fn parse_module_block(
                     _ => (
                         garbage_pipeline(&pipeline.commands[0].parts),
                         Some(ParseError::UnexpectedKeyword(
-                            "expected def or export keyword".into(),
+                            "expected def, module, or export keyword".into(),
                             pipeline.commands[0].parts[0],
                         )),
                     ),
@@ -1211,6 +1303,113 @@ pub fn parse_module(
     }
 }
 
+/// Collect all decls and aliases exported from a module, optionally prefixed
+/// with `head`. Exports of submodules are prefixed with the submodule name so
+/// that `use spam` brings in `spam eggs foo` for a submodule `eggs`.
+fn gather_overlay_exports(
+    working_set: &StateWorkingSet,
+    overlay: &Overlay,
+    head: Option<&[u8]>,
+) -> (Vec<(Vec<u8>, DeclId)>, Vec<(Vec<u8>, AliasId)>) {
+    let (mut decls, mut aliases) = match head {
+        Some(head) => (
+            overlay.decls_with_head(head),
+            overlay.aliases_with_head(head),
+        ),
+        None => (overlay.decls(), overlay.aliases()),
+    };
+
+    for (sub_name, sub_id) in &overlay.overlays {
+        let sub_head = match head {
+            Some(head) => {
+                let mut new_head = head.to_vec();
+                new_head.push(b' ');
+                new_head.extend(sub_name);
+                new_head
+            }
+            None => sub_name.clone(),
+        };
+
+        let (sub_decls, sub_aliases) = gather_overlay_exports(
+            working_set,
+            working_set.get_overlay(*sub_id),
+            Some(&sub_head),
+        );
+
+        decls.extend(sub_decls);
+        aliases.extend(sub_aliases);
+    }
+
+    (decls, aliases)
+}
+
+/// Resolve the member chain of an import pattern against a module, descending
+/// into exported submodules as names match them.
+fn resolve_import_pattern_members(
+    working_set: &StateWorkingSet,
+    overlay: &Overlay,
+    head_name: &[u8],
+    members: &[ImportPatternMember],
+    error: &mut Option<ParseError>,
+) -> (Vec<(Vec<u8>, DeclId)>, Vec<(Vec<u8>, AliasId)>) {
+    match members.first() {
+        None => gather_overlay_exports(working_set, overlay, Some(head_name)),
+        Some(ImportPatternMember::Glob { .. }) => {
+            gather_overlay_exports(working_set, overlay, None)
+        }
+        Some(ImportPatternMember::Name { name, span }) => {
+            if let Some(sub_id) = overlay.get_overlay_id(name) {
+                resolve_import_pattern_members(
+                    working_set,
+                    working_set.get_overlay(sub_id),
+                    name,
+                    &members[1..],
+                    error,
+                )
+            } else {
+                let mut decl_output = vec![];
+                let mut alias_output = vec![];
+
+                if let Some(id) = overlay.get_decl_id(name) {
+                    decl_output.push((name.clone(), id));
+                } else if let Some(id) = overlay.get_alias_id(name) {
+                    alias_output.push((name.clone(), id));
+                } else if !overlay.has_env_var(name) {
+                    *error = error.take().or(Some(ParseError::ExportNotFound(*span)));
+                }
+
+                (decl_output, alias_output)
+            }
+        }
+        Some(ImportPatternMember::List { names }) => {
+            let mut decl_output = vec![];
+            let mut alias_output = vec![];
+
+            for (name, span) in names {
+                if let Some(id) = overlay.get_decl_id(name) {
+                    decl_output.push((name.clone(), id));
+                } else if let Some(id) = overlay.get_alias_id(name) {
+                    alias_output.push((name.clone(), id));
+                } else if let Some(sub_id) = overlay.get_overlay_id(name) {
+                    let (sub_decls, sub_aliases) = gather_overlay_exports(
+                        working_set,
+                        working_set.get_overlay(sub_id),
+                        Some(name),
+                    );
+
+                    decl_output.extend(sub_decls);
+                    alias_output.extend(sub_aliases);
+                } else if !overlay.has_env_var(name) {
+                    *error = error.take().or(Some(ParseError::ExportNotFound(*span)));
+                    break;
+                }
+            }
+
+            (decl_output, alias_output)
+        }
+    }
+}
+
 pub fn parse_use(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -1366,47 +1565,13 @@ pub fn parse_use(
             }
         };
 
-    let (decls_to_use, aliases_to_use) = if import_pattern.members.is_empty() {
-        (
-            overlay.decls_with_head(&import_pattern.head.name),
-            overlay.aliases_with_head(&import_pattern.head.name),
-        )
-    } else {
-        match &import_pattern.members[0] {
-            ImportPatternMember::Glob { .. } => (overlay.decls(), overlay.aliases()),
-            ImportPatternMember::Name { name, span } => {
-                let mut decl_output = vec![];
-                let mut alias_output = vec![];
-
-                if let Some(id) = overlay.get_decl_id(name) {
-                    decl_output.push((name.clone(), id));
-                } else if let Some(id) = overlay.get_alias_id(name) {
-                    alias_output.push((name.clone(), id));
-                } else if !overlay.has_env_var(name) {
-                    error = error.or(Some(ParseError::ExportNotFound(*span)))
-                }
-
-                (decl_output, alias_output)
-            }
-            ImportPatternMember::List { names } => {
-                let mut decl_output = vec![];
-                let mut alias_output = vec![];
-
-                for (name, span) in names {
-                    if let Some(id) = overlay.get_decl_id(name) {
-                        decl_output.push((name.clone(), id));
-                    } else if let Some(id) = overlay.get_alias_id(name) {
-                        alias_output.push((name.clone(), id));
-                    } else if !overlay.has_env_var(name) {
-                        error = error.or(Some(ParseError::ExportNotFound(*span)));
-                        break;
-                    }
-                }
-
-                (decl_output, alias_output)
-            }
-        }
-    };
+    let (decls_to_use, aliases_to_use) = resolve_import_pattern_members(
+        working_set,
+        &overlay,
+        &import_pattern.head.name,
+        &import_pattern.members,
+        &mut error,
+    );
 
     // Extend the current scope with the module's overlay
     working_set.use_decls(decls_to_use);
@@ -1660,14 +1825,20 @@ pub fn parse_let(
     spans: &[Span],
     expand_aliases_denylist: &[usize],
 ) -> (Pipeline, Option<ParseError>) {
-    let name = working_set.get_span_contents(spans[0]);
+    let name = working_set.get_span_contents(spans[0]).to_vec();
+
+    // `mut` parses the same as `let`, except that the variable it creates can
+    // be changed later with the assignment operators. `const` additionally
+    // evaluates its value at parse time and records it on the variable.
+    if name == b"let" || name == b"mut" || name == b"const" {
+        let mutable = name == b"mut";
+        let constant = name == b"const";
 
-    if name == b"let" {
         if let Some((span, err)) = check_name(working_set, spans) {
             return (Pipeline::from_vec(vec![garbage(*span)]), Some(err));
         }
 
-        if let Some(decl_id) = working_set.find_decl(b"let") {
+        if let Some(decl_id) = working_set.find_decl(&name) {
             let cmd = working_set.get_decl(decl_id);
             let call_signature = cmd.signature().call_signature();
 
@@ -1697,8 +1868,12 @@ pub fn parse_let(
                         }
 
                         let mut idx = 0;
-                        let (lvalue, err) =
-                            parse_var_with_opt_type(working_set, &spans[1..(span.0)], &mut idx);
+                        let (lvalue, err) = parse_var_with_opt_type(
+                            working_set,
+                            &spans[1..(span.0)],
+                            &mut idx,
+                            mutable,
+                        );
                         error = error.or(err);
 
                         let var_id = lvalue.as_var();
@@ -1709,6 +1884,23 @@ pub fn parse_let(
                             if var_id != CONFIG_VARIABLE_ID {
                                 working_set.set_variable_type(var_id, rhs_type);
                             }
+
+                            if constant {
+                                let keyword_value = rvalue
+                                    .as_keyword()
+                                    .map(|expr| eval_constant(working_set, expr));
+
+                                match keyword_value {
+                                    Some(Ok(value)) => {
+                                        working_set.set_variable_const_val(var_id, value)
+                                    }
+                                    Some(Err(err)) => error = error.or(Some(err)),
+                                    None => {
+                                        error =
+                                            error.or(Some(ParseError::NotAConstant(rvalue.span)))
+                                    }
+                                }
+                            }
                         }
 
                         let call = Box::new(Call {
@@ -1764,6 +1956,83 @@ pub fn parse_let(
     )
 }
 
+/// Evaluate a subset of expressions at parse time: the literal forms plus
+/// references to other parse-time constants. Anything else is not a constant.
+pub fn eval_constant(
+    working_set: &StateWorkingSet,
+    expr: &Expression,
+) -> Result<Value, ParseError> {
+    match &expr.expr {
+        Expr::Bool(b) => Ok(Value::Bool {
+            val: *b,
+            span: expr.span,
+        }),
+        Expr::Int(i) => Ok(Value::Int {
+            val: *i,
+            span: expr.span,
+        }),
+        Expr::Float(f) => Ok(Value::Float {
+            val: *f,
+            span: expr.span,
+        }),
+        Expr::Binary(b) => Ok(Value::Binary {
+            val: b.clone(),
+            span: expr.span,
+        }),
+        Expr::Filepath(path) => Ok(Value::String {
+            val: path.clone(),
+            span: expr.span,
+        }),
+        Expr::String(s) => Ok(Value::String {
+            val: s.clone(),
+            span: expr.span,
+        }),
+        Expr::DateTime(dt) => Ok(Value::Date {
+            val: *dt,
+            span: expr.span,
+        }),
+        Expr::Nothing => Ok(Value::Nothing { span: expr.span }),
+        Expr::Var(var_id) => working_set
+            .get_variable(*var_id)
+            .const_val
+            .clone()
+            .ok_or(ParseError::NotAConstant(expr.span)),
+        Expr::FullCellPath(cell_path) if cell_path.tail.is_empty() => {
+            eval_constant(working_set, &cell_path.head)
+        }
+        Expr::List(exprs) => {
+            let mut vals = vec![];
+            for expr in exprs {
+                vals.push(eval_constant(working_set, expr)?);
+            }
+
+            Ok(Value::List {
+                vals,
+                span: expr.span,
+            })
+        }
+        Expr::Record(fields) => {
+            let mut cols = vec![];
+            let mut vals = vec![];
+            for (col, val) in fields {
+                cols.push(
+                    eval_constant(working_set, col)?
+                        .as_string()
+                        .map_err(|_| ParseError::NotAConstant(col.span))?,
+                );
+                vals.push(eval_constant(working_set, val)?);
+            }
+
+            Ok(Value::Record {
+                cols,
+                vals,
+                span: expr.span,
+            })
+        }
+        _ => Err(ParseError::NotAConstant(expr.span)),
+    }
+}
+
 pub fn parse_source(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -1802,8 +2071,20 @@ pub fn parse_source(
             if spans.len() >= 2 {
                 let name_expr = working_set.get_span_contents(spans[1]);
                 let name_expr = trim_quotes(name_expr);
-                if let Ok(filename) = String::from_utf8(name_expr.to_vec()) {
-                    if let Some(path) = find_in_dirs(&filename, working_set, &cwd, LIB_DIRS_ENV) {
+
+                // The file name can also come from a parse-time constant
+                let filename = if name_expr.starts_with(b"$") {
+                    working_set
+                        .find_variable(name_expr)
+                        .and_then(|var_id| working_set.get_variable(var_id).const_val.clone())
+                        .and_then(|val| val.as_string().ok())
+                        .ok_or(ParseError::NotAConstant(spans[1]))
+                } else {
+                    String::from_utf8(name_expr.to_vec()).map_err(|_| ParseError::NonUtf8(spans[1]))
+                };
+
+                if let Ok(filename) = filename.as_ref() {
+                    if let Some(path) = find_in_dirs(filename, working_set, &cwd, LIB_DIRS_ENV) {
                         if let Ok(contents) = std::fs::read(&path) {
                             // This will load the defs from the file into the
                             // working set, if it was a successful parse.
@@ -1854,10 +2135,13 @@ pub fn parse_source(
                             }
                         }
                     } else {
-                        error = error.or(Some(ParseError::SourcedFileNotFound(filename, spans[1])));
+                        error = error.or(Some(ParseError::SourcedFileNotFound(
+                            filename.clone(),
+                            spans[1],
+                        )));
                     }
-                } else {
-                    return (garbage_pipeline(spans), Some(ParseError::NonUtf8(spans[1])));
+                } else if let Err(err) = filename {
+                    return (garbage_pipeline(spans), Some(err));
                 }
             }
             return (
@@ -2116,6 +2400,18 @@ fn find_in_dirs(
                         }
                     }
 
+                    None
+                } else if let Ok(dirs) = lib_dirs.as_string() {
+                    // An env var inherited from the parent process is a plain
+                    // string; treat it as a PATH-style list of directories
+                    for lib_dir in std::env::split_paths(&dirs) {
+                        if let Ok(dir_abs) = canonicalize_with(&lib_dir, cwd) {
+                            if let Ok(path) = canonicalize_with(filename, dir_abs) {
+                                return Some(path);
+                            }
+                        }
+                    }
+
                     None
                 } else {
                     None
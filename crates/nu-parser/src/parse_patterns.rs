@@ -0,0 +1,261 @@
+use crate::{
+    lex,
+    parser::{is_variable, parse_value},
+    ParseError,
+};
+use nu_protocol::{
+    ast::{MatchPattern, Pattern},
+    engine::StateWorkingSet,
+    Span, SyntaxShape, Type,
+};
+
+pub fn garbage(span: Span) -> MatchPattern {
+    MatchPattern {
+        pattern: Pattern::Garbage,
+        guard: None,
+        span,
+    }
+}
+
+pub fn parse_pattern(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+) -> (MatchPattern, Option<ParseError>) {
+    let bytes = working_set.get_span_contents(span);
+
+    if bytes == b"_" {
+        (
+            MatchPattern {
+                pattern: Pattern::IgnoreValue,
+                guard: None,
+                span,
+            },
+            None,
+        )
+    } else if bytes.starts_with(b"$") {
+        parse_variable_pattern(working_set, span)
+    } else if bytes.starts_with(b"{") {
+        parse_record_pattern(working_set, span)
+    } else if bytes.starts_with(b"[") {
+        parse_list_pattern(working_set, span)
+    } else if let Some(ty) = type_of_pattern(bytes) {
+        (
+            MatchPattern {
+                pattern: Pattern::Type(ty),
+                guard: None,
+                span,
+            },
+            None,
+        )
+    } else {
+        // A literal value to compare against, eg `3` or `1..10`
+        let (value, error) = parse_value(working_set, span, &SyntaxShape::Any, &[]);
+
+        (
+            MatchPattern {
+                pattern: Pattern::Value(value),
+                guard: None,
+                span,
+            },
+            error,
+        )
+    }
+}
+
+fn type_of_pattern(bytes: &[u8]) -> Option<Type> {
+    match bytes {
+        b"binary" => Some(Type::Binary),
+        b"block" => Some(Type::Block),
+        b"bool" => Some(Type::Bool),
+        b"date" => Some(Type::Date),
+        b"duration" => Some(Type::Duration),
+        b"filesize" => Some(Type::Filesize),
+        b"float" => Some(Type::Float),
+        b"int" => Some(Type::Int),
+        b"list" => Some(Type::List(Box::new(Type::Any))),
+        b"nothing" => Some(Type::Nothing),
+        b"number" => Some(Type::Number),
+        b"range" => Some(Type::Range),
+        b"record" => Some(Type::Record(vec![])),
+        b"string" => Some(Type::String),
+        b"table" => Some(Type::Table(vec![])),
+        _ => None,
+    }
+}
+
+pub fn parse_variable_pattern(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+) -> (MatchPattern, Option<ParseError>) {
+    let bytes = working_set.get_span_contents(span);
+
+    if is_variable(bytes) {
+        let var_id = working_set.add_variable(bytes.to_vec(), span, Type::Any, false);
+
+        (
+            MatchPattern {
+                pattern: Pattern::Variable(var_id),
+                guard: None,
+                span,
+            },
+            None,
+        )
+    } else {
+        (
+            garbage(span),
+            Some(ParseError::Expected("valid variable name".into(), span)),
+        )
+    }
+}
+
+pub fn parse_list_pattern(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+) -> (MatchPattern, Option<ParseError>) {
+    let bytes = working_set.get_span_contents(span);
+
+    let mut error = None;
+
+    let mut start = span.start;
+    let mut end = span.end;
+
+    if bytes.starts_with(b"[") {
+        start += 1;
+    }
+    if bytes.ends_with(b"]") {
+        end -= 1;
+    } else {
+        error = error.or_else(|| Some(ParseError::Unclosed("]".into(), Span { start: end, end })));
+    }
+
+    let inner_span = Span { start, end };
+    let source = working_set.get_span_contents(inner_span);
+
+    let (output, err) = lex(source, inner_span.start, &[b'\n', b'\r', b','], &[], true);
+    error = error.or(err);
+
+    let mut args = vec![];
+
+    for (idx, token) in output.iter().enumerate() {
+        let contents = working_set.get_span_contents(token.span);
+
+        if contents == b".." {
+            // The rest of the list, ignored
+            args.push(MatchPattern {
+                pattern: Pattern::IgnoreRest,
+                guard: None,
+                span: token.span,
+            });
+        } else if contents.starts_with(b"..$") {
+            // The rest of the list, bound to a variable
+            let name = contents[2..].to_vec();
+            let var_id = working_set.add_variable(name, token.span, Type::Any, false);
+
+            args.push(MatchPattern {
+                pattern: Pattern::Rest(var_id),
+                guard: None,
+                span: token.span,
+            });
+        } else {
+            let (pattern, err) = parse_pattern(working_set, token.span);
+            error = error.or(err);
+            args.push(pattern);
+            continue;
+        }
+
+        if idx != output.len() - 1 {
+            error = error.or(Some(ParseError::Expected(
+                "end of list".into(),
+                Span {
+                    start: token.span.end,
+                    end: inner_span.end,
+                },
+            )));
+        }
+    }
+
+    (
+        MatchPattern {
+            pattern: Pattern::List(args),
+            guard: None,
+            span,
+        },
+        error,
+    )
+}
+
+pub fn parse_record_pattern(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+) -> (MatchPattern, Option<ParseError>) {
+    let bytes = working_set.get_span_contents(span);
+
+    let mut error = None;
+
+    let mut start = span.start;
+    let mut end = span.end;
+
+    if bytes.starts_with(b"{") {
+        start += 1;
+    } else {
+        error = error.or_else(|| {
+            Some(ParseError::Expected(
+                "{".into(),
+                Span {
+                    start,
+                    end: start + 1,
+                },
+            ))
+        });
+    }
+    if bytes.ends_with(b"}") {
+        end -= 1;
+    } else {
+        error = error.or_else(|| Some(ParseError::Unclosed("}".into(), Span { start: end, end })));
+    }
+
+    let inner_span = Span { start, end };
+    let source = working_set.get_span_contents(inner_span);
+
+    let (tokens, err) = lex(source, start, &[b'\n', b'\r', b','], &[b':'], true);
+    error = error.or(err);
+
+    let mut output = vec![];
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let field = String::from_utf8_lossy(working_set.get_span_contents(tokens[idx].span))
+            .trim_matches(|c| c == '"' || c == '\'')
+            .to_string();
+
+        idx += 1;
+        if idx == tokens.len() {
+            return (
+                garbage(span),
+                Some(ParseError::Expected("record pattern".into(), span)),
+            );
+        }
+        let colon = working_set.get_span_contents(tokens[idx].span);
+        idx += 1;
+        if idx == tokens.len() || colon != b":" {
+            return (
+                garbage(span),
+                Some(ParseError::Expected("record pattern".into(), span)),
+            );
+        }
+        let (pattern, err) = parse_pattern(working_set, tokens[idx].span);
+        error = error.or(err);
+        idx += 1;
+
+        output.push((field, pattern));
+    }
+
+    (
+        MatchPattern {
+            pattern: Pattern::Record(output),
+            guard: None,
+            span,
+        },
+        error,
+    )
+}
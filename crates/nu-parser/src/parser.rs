@@ -1,7 +1,8 @@
 use crate::{
     lex, lite_parse,
-    lite_parse::LiteCommand,
+    lite_parse::{LiteCommand, LitePipeline},
     parse_keywords::{parse_extern, parse_for, parse_source},
+    parse_patterns::parse_pattern,
     type_check::{math_result_type, type_compatible},
     LiteBlock, ParseError, Token, TokenContents,
 };
@@ -107,7 +108,14 @@ fn is_identifier(bytes: &[u8]) -> bool {
     bytes.iter().all(|x| is_identifier_byte(*x))
 }
 
-fn is_variable(bytes: &[u8]) -> bool {
+/// Whether a token is a spread, eg `...$args`, `...[1 2 3]` or `...(gen-args)`
+fn is_spread(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"...")
+        && bytes.len() > 3
+        && (bytes[3] == b'$' || bytes[3] == b'[' || bytes[3] == b'(')
+}
+
+pub(crate) fn is_variable(bytes: &[u8]) -> bool {
     if bytes.len() > 1 && bytes[0] == b'$' {
         is_identifier(&bytes[1..])
     } else {
@@ -275,7 +283,26 @@ pub fn parse_external_call(
     for span in &spans[1..] {
         let contents = working_set.get_span_contents(*span);
 
-        if contents.starts_with(b"$") || contents.starts_with(b"(") {
+        if is_spread(contents) {
+            // Spread a list into the external's arguments, eg `^echo ...$args`
+            let inner_span = Span {
+                start: span.start + 3,
+                end: span.end,
+            };
+            let (inner, err) = parse_value(
+                working_set,
+                inner_span,
+                &SyntaxShape::List(Box::new(SyntaxShape::Any)),
+                expand_aliases_denylist,
+            );
+            error = error.or(err);
+            args.push(Expression {
+                expr: Expr::Spread(Box::new(inner)),
+                span: *span,
+                ty: Type::Any,
+                custom_completion: None,
+            });
+        } else if contents.starts_with(b"$") || contents.starts_with(b"(") {
             let (arg, err) = parse_dollar_expr(working_set, *span, expand_aliases_denylist);
             error = error.or(err);
             args.push(arg);
@@ -579,7 +606,7 @@ pub fn parse_multispan_value(
         SyntaxShape::VarWithOptType => {
             trace!("parsing: var with opt type");
 
-            let (arg, err) = parse_var_with_opt_type(working_set, spans, spans_idx);
+            let (arg, err) = parse_var_with_opt_type(working_set, spans, spans_idx, false);
             error = error.or(err);
 
             (arg, error)
@@ -831,6 +858,34 @@ pub fn parse_internal_call(
 
         // Parse a positional arg if there is one
         if let Some(positional) = signature.get_positional(positional_idx) {
+            // Spread a list into the rest parameter, eg `foo ...$args`
+            if is_spread(working_set.get_span_contents(arg_span))
+                && positional_idx
+                    >= signature.required_positional.len() + signature.optional_positional.len()
+            {
+                let inner_span = Span {
+                    start: arg_span.start + 3,
+                    end: arg_span.end,
+                };
+                let (inner, err) = parse_value(
+                    working_set,
+                    inner_span,
+                    &SyntaxShape::List(Box::new(positional.shape.clone())),
+                    expand_aliases_denylist,
+                );
+                error = error.or(err);
+
+                call.add_positional(Expression {
+                    expr: Expr::Spread(Box::new(inner)),
+                    span: arg_span,
+                    ty: Type::Any,
+                    custom_completion: None,
+                });
+
+                spans_idx += 1;
+                continue;
+            }
+
             let end = calculate_end_span(working_set, &signature, spans, spans_idx, positional_idx);
 
             let end = if spans.len() > spans_idx && end == spans_idx {
@@ -968,6 +1023,51 @@ pub fn parse_call(
                 let mut expand_aliases_denylist = expand_aliases_denylist.to_vec();
                 expand_aliases_denylist.push(alias_id);
 
+                // An alias may expand to a whole pipeline. Parse such a
+                // replacement as a subexpression so it still fits in a single
+                // call position; any trailing arguments end up attached to the
+                // last command of the replacement.
+                if new_spans
+                    .iter()
+                    .any(|s| working_set.get_span_contents(*s) == b"|")
+                {
+                    let mut lite_pipeline = LitePipeline::new();
+                    let mut lite_command = LiteCommand::new();
+
+                    for new_span in &new_spans {
+                        if working_set.get_span_contents(*new_span) == b"|" {
+                            lite_pipeline.push(lite_command);
+                            lite_command = LiteCommand::new();
+                        } else {
+                            lite_command.push(*new_span);
+                        }
+                    }
+                    lite_pipeline.push(lite_command);
+
+                    let mut lite_block = LiteBlock::new();
+                    lite_block.push(lite_pipeline);
+
+                    let (block, err) = parse_block(
+                        working_set,
+                        &lite_block,
+                        true,
+                        &expand_aliases_denylist,
+                        true,
+                    );
+
+                    let block_id = working_set.add_block(block);
+
+                    return (
+                        Expression {
+                            expr: Expr::Subexpression(block_id),
+                            span: span(spans),
+                            ty: Type::Any,
+                            custom_completion: None,
+                        },
+                        err,
+                    );
+                }
+
                 let lite_command = LiteCommand {
                     comments: vec![],
                     parts: new_spans.clone(),
@@ -1074,26 +1174,48 @@ pub fn parse_binary(
     working_set: &mut StateWorkingSet,
     span: Span,
 ) -> (Expression, Option<ParseError>) {
-    pub fn decode_hex(s: &str) -> Result<Vec<u8>, ParseIntError> {
-        (0..s.len())
-            .step_by(2)
-            .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
-            .collect()
+    let contents = working_set.get_span_contents(span);
+    if contents.starts_with(b"0x[") {
+        parse_binary_with_base(working_set, span, 16, 2, b"0x[", b"]")
+    } else if contents.starts_with(b"0o[") {
+        parse_binary_with_base(working_set, span, 8, 3, b"0o[", b"]")
+    } else if contents.starts_with(b"0b[") {
+        parse_binary_with_base(working_set, span, 2, 8, b"0b[", b"]")
+    } else {
+        (
+            garbage(span),
+            Some(ParseError::Expected("binary".into(), span)),
+        )
     }
+}
 
+fn parse_binary_with_base(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+    base: u32,
+    min_digits_per_byte: usize,
+    prefix: &[u8],
+    suffix: &[u8],
+) -> (Expression, Option<ParseError>) {
     let token = working_set.get_span_contents(span);
 
-    if let Some(token) = token.strip_prefix(b"0x[") {
-        if let Some(token) = token.strip_suffix(b"]") {
-            let (lexed, err) = lex(token, span.start + 3, &[b',', b'\r', b'\n'], &[], true);
+    if let Some(token) = token.strip_prefix(prefix) {
+        if let Some(token) = token.strip_suffix(suffix) {
+            let (lexed, err) = lex(
+                token,
+                span.start + prefix.len(),
+                &[b',', b'\r', b'\n'],
+                &[],
+                true,
+            );
 
-            let mut hex_value = vec![];
+            let mut binary_value = vec![];
             for token in lexed {
                 match token.contents {
                     TokenContents::Item => {
                         let contents = working_set.get_span_contents(token.span);
 
-                        hex_value.extend_from_slice(contents);
+                        binary_value.extend_from_slice(contents);
                     }
                     TokenContents::Pipe => {
                         return (
@@ -1105,20 +1227,23 @@ pub fn parse_binary(
                 }
             }
 
-            if hex_value.len() % 2 != 0 {
+            if binary_value.len() % min_digits_per_byte != 0 {
                 return (
                     garbage(span),
                     Some(ParseError::IncorrectValue(
                         "incomplete binary".into(),
                         span,
-                        "number of binary digits needs to be a multiple of 2".into(),
+                        format!(
+                            "number of binary digits needs to be a multiple of {}",
+                            min_digits_per_byte
+                        ),
                     )),
                 );
             }
 
-            let str = String::from_utf8_lossy(&hex_value).to_string();
+            let str = String::from_utf8_lossy(&binary_value).to_string();
 
-            match decode_hex(&str) {
+            match decode_with_base(&str, base, min_digits_per_byte) {
                 Ok(v) => {
                     return (
                         Expression {
@@ -1149,6 +1274,13 @@ pub fn parse_binary(
     )
 }
 
+fn decode_with_base(s: &str, base: u32, digits_per_byte: usize) -> Result<Vec<u8>, ParseIntError> {
+    (0..s.len())
+        .step_by(digits_per_byte)
+        .map(|i| u8::from_str_radix(&s[i..i + digits_per_byte], base))
+        .collect()
+}
+
 pub fn parse_int(token: &[u8], span: Span) -> (Expression, Option<ParseError>) {
     if let Some(token) = token.strip_prefix(b"0x") {
         if let Ok(v) = i64::from_str_radix(&String::from_utf8_lossy(token), 16) {
@@ -1263,6 +1395,26 @@ pub fn parse_number(token: &[u8], span: Span) -> (Expression, Option<ParseError>
     }
 }
 
+/// A bound of a range can be a number literal, a variable, or a subexpression
+fn parse_range_bound(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+    expand_aliases_denylist: &[usize],
+) -> (Expression, Option<ParseError>) {
+    let bytes = working_set.get_span_contents(span);
+
+    if bytes.starts_with(b"$") || bytes.starts_with(b"(") {
+        parse_full_cell_path(working_set, None, span, expand_aliases_denylist)
+    } else {
+        parse_value(
+            working_set,
+            span,
+            &SyntaxShape::Number,
+            expand_aliases_denylist,
+        )
+    }
+}
+
 pub fn parse_range(
     working_set: &mut StateWorkingSet,
     span: Span,
@@ -1275,6 +1427,7 @@ pub fn parse_range(
     //   and  <range_operator> is ".." or "..<"
     //   and one of the <from> or <to> bounds must be present (just '..' is not allowed since it
     //     looks like parent directory)
+    //   and a bound can be a number literal, a variable, or a subexpression
 
     let contents = working_set.get_span_contents(span);
 
@@ -1294,8 +1447,26 @@ pub fn parse_range(
         );
     }
 
-    // First, figure out what exact operators are used and determine their positions
-    let dotdot_pos: Vec<_> = token.match_indices("..").map(|(pos, _)| pos).collect();
+    // First, figure out what exact operators are used and determine their positions,
+    // ignoring any ".." nested inside parens or brackets so that subexpression and
+    // cell path bounds parse correctly
+    let mut dotdot_pos = vec![];
+    let token_bytes = token.as_bytes();
+    let mut depth = 0i32;
+    let mut pos = 0;
+    while pos < token_bytes.len() {
+        match token_bytes[pos] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b'.' if depth == 0 && token_bytes.get(pos + 1) == Some(&b'.') => {
+                dotdot_pos.push(pos);
+                pos += 2;
+                continue;
+            }
+            _ => {}
+        }
+        pos += 1;
+    }
 
     let (next_op_pos, range_op_pos) =
         match dotdot_pos.len() {
@@ -1345,12 +1516,7 @@ pub fn parse_range(
         None
     } else {
         let from_span = Span::new(span.start, span.start + dotdot_pos[0]);
-        match parse_value(
-            working_set,
-            from_span,
-            &SyntaxShape::Number,
-            expand_aliases_denylist,
-        ) {
+        match parse_range_bound(working_set, from_span, expand_aliases_denylist) {
             (expression, None) => Some(Box::new(expression)),
             _ => {
                 return (
@@ -1365,12 +1531,7 @@ pub fn parse_range(
         None
     } else {
         let to_span = Span::new(range_op_span.end, span.end);
-        match parse_value(
-            working_set,
-            to_span,
-            &SyntaxShape::Number,
-            expand_aliases_denylist,
-        ) {
+        match parse_range_bound(working_set, to_span, expand_aliases_denylist) {
             (expression, None) => Some(Box::new(expression)),
             _ => {
                 return (
@@ -1397,12 +1558,7 @@ pub fn parse_range(
         let next_op_span = Span::new(span.start + pos, span.start + pos + "..".len());
         let next_span = Span::new(next_op_span.end, range_op_span.start);
 
-        match parse_value(
-            working_set,
-            next_span,
-            &SyntaxShape::Number,
-            expand_aliases_denylist,
-        ) {
+        match parse_range_bound(working_set, next_span, expand_aliases_denylist) {
             (expression, None) => (Some(Box::new(expression)), next_op_span),
             _ => {
                 return (
@@ -2632,100 +2788,61 @@ pub fn parse_import_pattern(
 
     let maybe_overlay_id = working_set.find_overlay(&head);
 
-    let (import_pattern, err) = if let Some(tail_span) = spans.get(1) {
-        // FIXME: expand this to handle deeper imports once we support module imports
+    // Each remaining span is one member of the pattern, so that deeper imports
+    // like `use spam eggs foo` can walk into exported submodules
+    let mut members = vec![];
+    let mut err = None;
+
+    for tail_span in spans[1..].iter() {
         let tail = working_set.get_span_contents(*tail_span);
+
         if tail == b"*" {
-            (
-                ImportPattern {
-                    head: ImportPatternHead {
-                        name: head,
-                        id: maybe_overlay_id,
-                        span: *head_span,
-                    },
-                    members: vec![ImportPatternMember::Glob { span: *tail_span }],
-                    hidden: HashSet::new(),
-                },
-                None,
-            )
+            members.push(ImportPatternMember::Glob { span: *tail_span });
         } else if tail.starts_with(b"[") {
-            let (result, err) = parse_list_expression(
+            let (result, list_err) = parse_list_expression(
                 working_set,
                 *tail_span,
                 &SyntaxShape::String,
                 expand_aliases_denylist,
             );
-            error = error.or(err);
-
-            let mut output = vec![];
+            error = error.or(list_err);
 
             match result {
                 Expression {
                     expr: Expr::List(list),
                     ..
                 } => {
+                    let mut output = vec![];
+
                     for l in list {
                         let contents = working_set.get_span_contents(l.span);
                         output.push((contents.to_vec(), l.span));
                     }
 
-                    (
-                        ImportPattern {
-                            head: ImportPatternHead {
-                                name: head,
-                                id: maybe_overlay_id,
-                                span: *head_span,
-                            },
-                            members: vec![ImportPatternMember::List { names: output }],
-                            hidden: HashSet::new(),
-                        },
-                        None,
-                    )
+                    members.push(ImportPatternMember::List { names: output });
+                }
+                _ => {
+                    err = err.or(Some(ParseError::ExportNotFound(result.span)));
                 }
-                _ => (
-                    ImportPattern {
-                        head: ImportPatternHead {
-                            name: head,
-                            id: maybe_overlay_id,
-                            span: *head_span,
-                        },
-                        members: vec![],
-                        hidden: HashSet::new(),
-                    },
-                    Some(ParseError::ExportNotFound(result.span)),
-                ),
             }
         } else {
             let tail = trim_quotes(tail);
-            (
-                ImportPattern {
-                    head: ImportPatternHead {
-                        name: head,
-                        id: maybe_overlay_id,
-                        span: *head_span,
-                    },
-                    members: vec![ImportPatternMember::Name {
-                        name: tail.to_vec(),
-                        span: *tail_span,
-                    }],
-                    hidden: HashSet::new(),
-                },
-                None,
-            )
+
+            members.push(ImportPatternMember::Name {
+                name: tail.to_vec(),
+                span: *tail_span,
+            });
         }
-    } else {
-        (
-            ImportPattern {
-                head: ImportPatternHead {
-                    name: head,
-                    id: maybe_overlay_id,
-                    span: *head_span,
-                },
-                members: vec![],
-                hidden: HashSet::new(),
-            },
-            None,
-        )
+    }
+
+    let import_pattern = ImportPattern {
+        head: ImportPatternHead {
+            name: head,
+            id: maybe_overlay_id,
+            span: *head_span,
+        },
+        members,
+        hidden: HashSet::new(),
     };
 
     (
@@ -2743,6 +2860,7 @@ pub fn parse_var_with_opt_type(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
     spans_idx: &mut usize,
+    mutable: bool,
 ) -> (Expression, Option<ParseError>) {
     let bytes = working_set.get_span_contents(spans[*spans_idx]).to_vec();
 
@@ -2769,6 +2887,7 @@ pub fn parse_var_with_opt_type(
                 bytes[0..(bytes.len() - 1)].to_vec(),
                 spans[*spans_idx - 1],
                 ty.clone(),
+                mutable,
             );
 
             (
@@ -2785,6 +2904,7 @@ pub fn parse_var_with_opt_type(
                 bytes[0..(bytes.len() - 1)].to_vec(),
                 spans[*spans_idx],
                 Type::Any,
+                mutable,
             );
             (
                 Expression {
@@ -2807,8 +2927,12 @@ pub fn parse_var_with_opt_type(
             None,
         )
     } else {
-        let id =
-            working_set.add_variable(bytes, span(&spans[*spans_idx..*spans_idx + 1]), Type::Any);
+        let id = working_set.add_variable(
+            bytes,
+            span(&spans[*spans_idx..*spans_idx + 1]),
+            Type::Any,
+            mutable,
+        );
 
         (
             Expression {
@@ -2847,7 +2971,7 @@ pub fn parse_row_condition(
     spans: &[Span],
     expand_aliases_denylist: &[usize],
 ) -> (Expression, Option<ParseError>) {
-    let var_id = working_set.add_variable(b"$it".to_vec(), span(spans), Type::Any);
+    let var_id = working_set.add_variable(b"$it".to_vec(), span(spans), Type::Any, false);
     let (expression, err) =
         parse_math_expression(working_set, spans, Some(var_id), expand_aliases_denylist);
     let span = span(spans);
@@ -3008,7 +3132,7 @@ pub fn parse_signature_helper(
                                 let long = String::from_utf8_lossy(&flags[0][2..]).to_string();
                                 let variable_name = flags[0][2..].to_vec();
                                 let var_id =
-                                    working_set.add_variable(variable_name, span, Type::Any);
+                                    working_set.add_variable(variable_name, span, Type::Any, false);
 
                                 if flags.len() == 1 {
                                     args.push(Arg::Flag(Flag {
@@ -3038,8 +3162,12 @@ pub fn parse_signature_helper(
                                     let chars: Vec<char> = short_flag.chars().collect();
                                     let long = String::from_utf8_lossy(&flags[0][2..]).to_string();
                                     let variable_name = flags[0][2..].to_vec();
-                                    let var_id =
-                                        working_set.add_variable(variable_name, span, Type::Any);
+                                    let var_id = working_set.add_variable(
+                                        variable_name,
+                                        span,
+                                        Type::Any,
+                                        false,
+                                    );
 
                                     if chars.len() == 1 {
                                         args.push(Arg::Flag(Flag {
@@ -3074,7 +3202,7 @@ pub fn parse_signature_helper(
                                 let len = chars[0].encode_utf8(&mut encoded_var_name).len();
                                 let variable_name = encoded_var_name[0..len].to_vec();
                                 let var_id =
-                                    working_set.add_variable(variable_name, span, Type::Any);
+                                    working_set.add_variable(variable_name, span, Type::Any, false);
 
                                 args.push(Arg::Flag(Flag {
                                     arg: None,
@@ -3132,7 +3260,8 @@ pub fn parse_signature_helper(
                                 let contents: Vec<_> = contents[..(contents.len() - 1)].into();
                                 let name = String::from_utf8_lossy(&contents).to_string();
 
-                                let var_id = working_set.add_variable(contents, span, Type::Any);
+                                let var_id =
+                                    working_set.add_variable(contents, span, Type::Any, false);
 
                                 // Positional arg, optional
                                 args.push(Arg::Positional(
@@ -3150,7 +3279,7 @@ pub fn parse_signature_helper(
                                 let contents_vec: Vec<u8> = contents.to_vec();
 
                                 let var_id =
-                                    working_set.add_variable(contents_vec, span, Type::Any);
+                                    working_set.add_variable(contents_vec, span, Type::Any, false);
 
                                 args.push(Arg::RestPositional(PositionalArg {
                                     desc: String::new(),
@@ -3164,7 +3293,7 @@ pub fn parse_signature_helper(
                                 let contents_vec = contents.to_vec();
 
                                 let var_id =
-                                    working_set.add_variable(contents_vec, span, Type::Any);
+                                    working_set.add_variable(contents_vec, span, Type::Any, false);
 
                                 // Positional arg, required
                                 args.push(Arg::Positional(
@@ -3280,7 +3409,12 @@ pub fn parse_signature_helper(
                                         if var_type != &Type::Bool {
                                             match var_type {
                                                 Type::Any => {
-                                                    *arg = Some(expression_ty.to_shape());
+                                                    // A boolean default makes the flag a
+                                                    // present/not-present switch, same as an
+                                                    // explicit `: bool` type
+                                                    if expression_ty != Type::Bool {
+                                                        *arg = Some(expression_ty.to_shape());
+                                                    }
                                                     working_set
                                                         .set_variable_type(var_id, expression_ty);
                                                 }
@@ -3423,6 +3557,45 @@ pub fn parse_list_expression(
             let mut spans_idx = 0;
 
             while spans_idx < arg.parts.len() {
+                let contents = working_set.get_span_contents(arg.parts[spans_idx]);
+                if is_spread(contents) {
+                    let inner_span = Span {
+                        start: arg.parts[spans_idx].start + 3,
+                        end: arg.parts[spans_idx].end,
+                    };
+                    let (inner, err) = parse_value(
+                        working_set,
+                        inner_span,
+                        &SyntaxShape::List(Box::new(element_shape.clone())),
+                        expand_aliases_denylist,
+                    );
+                    error = error.or(err);
+
+                    let ty = if let Type::List(ty) = &inner.ty {
+                        (**ty).clone()
+                    } else {
+                        Type::Any
+                    };
+
+                    args.push(Expression {
+                        expr: Expr::Spread(Box::new(inner)),
+                        span: arg.parts[spans_idx],
+                        ty: ty.clone(),
+                        custom_completion: None,
+                    });
+
+                    if let Some(ref ctype) = contained_type {
+                        if *ctype != ty {
+                            contained_type = Some(Type::Any);
+                        }
+                    } else {
+                        contained_type = Some(ty);
+                    }
+
+                    spans_idx += 1;
+                    continue;
+                }
+
                 let (arg, err) = parse_multispan_value(
                     working_set,
                     &arg.parts,
@@ -3733,6 +3906,131 @@ pub fn parse_block_expression(
     )
 }
 
+pub fn parse_match_block_expression(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+    expand_aliases_denylist: &[usize],
+) -> (Expression, Option<ParseError>) {
+    let bytes = working_set.get_span_contents(span);
+
+    let mut error = None;
+
+    let mut start = span.start;
+    let mut end = span.end;
+
+    if bytes.starts_with(b"{") {
+        start += 1;
+    } else {
+        return (
+            garbage(span),
+            Some(ParseError::Expected("match block".into(), span)),
+        );
+    }
+    if bytes.ends_with(b"}") {
+        end -= 1;
+    } else {
+        error = error.or_else(|| Some(ParseError::Unclosed("}".into(), Span { start: end, end })));
+    }
+
+    let inner_span = Span { start, end };
+    let source = working_set.get_span_contents(inner_span);
+
+    let (output, err) = lex(source, start, &[b'\n', b'\r', b','], &[], true);
+    error = error.or(err);
+
+    let mut position = 0;
+
+    let mut output_matches = vec![];
+
+    while position < output.len() {
+        // Each arm gets its own scope so that pattern variables don't leak into later arms
+        working_set.enter_scope();
+
+        let (mut pattern, err) = parse_pattern(working_set, output[position].span);
+        error = error.or(err);
+
+        position += 1;
+
+        if position < output.len() && working_set.get_span_contents(output[position].span) == b"if"
+        {
+            // An optional guard expression sits between the `if` and the `=>`
+            let guard_start = position + 1;
+            let mut guard_end = guard_start;
+
+            while guard_end < output.len()
+                && working_set.get_span_contents(output[guard_end].span) != b"=>"
+            {
+                guard_end += 1;
+            }
+
+            let guard_spans: Vec<Span> = output[guard_start..guard_end]
+                .iter()
+                .map(|t| t.span)
+                .collect();
+
+            if guard_spans.is_empty() {
+                error = error.or(Some(ParseError::Expected(
+                    "guard expression".into(),
+                    output[position].span,
+                )));
+            } else {
+                let (guard, err) =
+                    parse_math_expression(working_set, &guard_spans, None, expand_aliases_denylist);
+                error = error.or(err);
+
+                pattern.guard = Some(guard);
+            }
+
+            position = guard_end;
+        }
+
+        if position >= output.len() || working_set.get_span_contents(output[position].span) != b"=>"
+        {
+            error = error.or(Some(ParseError::Mismatch(
+                "=>".into(),
+                "match arm".into(),
+                pattern.span,
+            )));
+        } else {
+            position += 1;
+        }
+
+        let arm = if position < output.len() {
+            let (arm, err) = parse_value(
+                working_set,
+                output[position].span,
+                &SyntaxShape::Any,
+                expand_aliases_denylist,
+            );
+            error = error.or(err);
+            position += 1;
+
+            arm
+        } else {
+            error = error.or(Some(ParseError::Expected(
+                "match result expression".into(),
+                pattern.span,
+            )));
+
+            garbage(pattern.span)
+        };
+
+        working_set.exit_scope();
+
+        output_matches.push((pattern, arm));
+    }
+
+    (
+        Expression {
+            expr: Expr::MatchBlock(output_matches),
+            span,
+            ty: Type::Any,
+            custom_completion: None,
+        },
+        error,
+    )
+}
+
 pub fn parse_value(
     working_set: &mut StateWorkingSet,
     span: Span,
@@ -3820,7 +4118,7 @@ pub fn parse_value(
             }
         }
         b'{' => {
-            if !matches!(shape, SyntaxShape::Block(..)) {
+            if !matches!(shape, SyntaxShape::Block(..) | SyntaxShape::MatchBlock) {
                 if let (expr, None) =
                     parse_full_cell_path(working_set, None, span, expand_aliases_denylist)
                 {
@@ -3829,6 +4127,8 @@ pub fn parse_value(
             }
             if matches!(shape, SyntaxShape::Block(_)) || matches!(shape, SyntaxShape::Any) {
                 return parse_block_expression(working_set, shape, span, expand_aliases_denylist);
+            } else if matches!(shape, SyntaxShape::MatchBlock) {
+                return parse_match_block_expression(working_set, span, expand_aliases_denylist);
             } else if matches!(shape, SyntaxShape::Record) {
                 return parse_record(working_set, span, expand_aliases_denylist);
             } else {
@@ -3900,6 +4200,10 @@ pub fn parse_value(
                 )
             }
         }
+        SyntaxShape::MatchBlock => (
+            Expression::garbage(span),
+            Some(ParseError::Expected("match block".into(), span)),
+        ),
         SyntaxShape::CellPath => {
             let source = working_set.get_span_contents(span);
             let mut error = None;
@@ -4001,6 +4305,11 @@ pub fn parse_operator(
         b"&&" => Operator::And,
         b"||" => Operator::Or,
         b"**" => Operator::Pow,
+        b"=" => Operator::Assign,
+        b"+=" => Operator::PlusAssign,
+        b"-=" => Operator::MinusAssign,
+        b"*=" => Operator::MultiplyAssign,
+        b"/=" => Operator::DivideAssign,
         _ => {
             return (
                 garbage(span),
@@ -4020,6 +4329,15 @@ pub fn parse_operator(
     )
 }
 
+/// The variable on the left-hand side of an assignment, if there is one
+fn var_id_of(expr: &Expression) -> Option<VarId> {
+    match &expr.expr {
+        Expr::Var(var_id) => Some(*var_id),
+        Expr::FullCellPath(cell_path) if cell_path.tail.is_empty() => var_id_of(&cell_path.head),
+        _ => None,
+    }
+}
+
 pub fn parse_math_expression(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -4098,6 +4416,27 @@ pub fn parse_math_expression(
         let (op, err) = parse_operator(working_set, spans[idx]);
         error = error.or(err);
 
+        if let Expr::Operator(
+            Operator::Assign
+            | Operator::PlusAssign
+            | Operator::MinusAssign
+            | Operator::MultiplyAssign
+            | Operator::DivideAssign,
+        ) = &op.expr
+        {
+            // Assignment only makes sense at the start of an expression, with
+            // a mutable variable on the left-hand side
+            let err = match expr_stack.first() {
+                Some(lhs) if expr_stack.len() == 1 => match var_id_of(lhs) {
+                    Some(var_id) if working_set.get_variable(var_id).mutable => None,
+                    Some(_) => Some(ParseError::AssignmentRequiresMutableVar(lhs.span)),
+                    None => Some(ParseError::AssignmentRequiresVar(lhs.span)),
+                },
+                _ => Some(ParseError::AssignmentRequiresVar(op.span)),
+            };
+            error = error.or(err);
+        }
+
         let op_prec = op.precedence();
 
         idx += 1;
@@ -4302,6 +4641,19 @@ pub fn parse_expression(
                 .0,
                 Some(ParseError::BuiltinCommandInPipeline("let".into(), spans[0])),
             ),
+            b"const" => (
+                parse_call(
+                    working_set,
+                    &spans[pos..],
+                    spans[0],
+                    expand_aliases_denylist,
+                )
+                .0,
+                Some(ParseError::BuiltinCommandInPipeline(
+                    "const".into(),
+                    spans[0],
+                )),
+            ),
             b"alias" => (
                 parse_call(
                     working_set,
@@ -4484,7 +4836,9 @@ pub fn parse_builtin_commands(
     match name {
         b"def" | b"def-env" => parse_def(working_set, lite_command, expand_aliases_denylist),
         b"extern" => parse_extern(working_set, lite_command, expand_aliases_denylist),
-        b"let" => parse_let(working_set, &lite_command.parts, expand_aliases_denylist),
+        b"let" | b"const" | b"mut" => {
+            parse_let(working_set, &lite_command.parts, expand_aliases_denylist)
+        }
         b"for" => {
             let (expr, err) = parse_for(working_set, &lite_command.parts, expand_aliases_denylist);
             (Pipeline::from_vec(vec![expr]), err)
@@ -4511,6 +4865,40 @@ pub fn parse_builtin_commands(
     }
 }
 
+/// Join the commands of a lite pipeline back into a single lite command,
+/// recovering a span for each pipe between them.
+fn merge_alias_pipeline(working_set: &StateWorkingSet, pipeline: &LitePipeline) -> LiteCommand {
+    let mut lite_command = LiteCommand::new();
+    lite_command.comments = pipeline.commands[0].comments.clone();
+
+    for command in &pipeline.commands {
+        if let (Some(prev), Some(next)) = (lite_command.parts.last(), command.parts.first()) {
+            // The pipe character sits in the source gap between the two
+            // commands; recover its span so the replacement re-parses as a
+            // pipeline when the alias is expanded.
+            let gap = Span {
+                start: prev.end,
+                end: next.start,
+            };
+
+            if let Some(offset) = working_set
+                .get_span_contents(gap)
+                .iter()
+                .position(|b| *b == b'|')
+            {
+                lite_command.push(Span {
+                    start: gap.start + offset,
+                    end: gap.start + offset + 1,
+                });
+            }
+        }
+
+        lite_command.parts.extend_from_slice(&command.parts);
+    }
+
+    lite_command
+}
+
 pub fn parse_record(
     working_set: &mut StateWorkingSet,
     span: Span,
@@ -4552,6 +4940,40 @@ pub fn parse_record(
     let mut idx = 0;
 
     while idx < tokens.len() {
+        let contents = working_set.get_span_contents(tokens[idx].span);
+        if is_spread(contents) {
+            // Spread a record inside this one, eg `{...$config, x: 1}`
+            let inner_span = Span {
+                start: tokens[idx].span.start + 3,
+                end: tokens[idx].span.end,
+            };
+            let (inner, err) = parse_value(
+                working_set,
+                inner_span,
+                &SyntaxShape::Any,
+                expand_aliases_denylist,
+            );
+            error = error.or(err);
+
+            output.push((
+                Expression {
+                    expr: Expr::Spread(Box::new(inner)),
+                    span: tokens[idx].span,
+                    ty: Type::Any,
+                    custom_completion: None,
+                },
+                Expression {
+                    expr: Expr::Nothing,
+                    span: tokens[idx].span,
+                    ty: Type::Nothing,
+                    custom_completion: None,
+                },
+            ));
+
+            idx += 1;
+            continue;
+        }
+
         let (field, err) = parse_value(
             working_set,
             tokens[idx].span,
@@ -4599,6 +5021,34 @@ pub fn parse_record(
     )
 }
 
+/// Check each command's declared input type against the shape the previous
+/// element of the pipeline is known to produce. Most commands declare `any`
+/// on one side or the other, which opts out of the check.
+fn check_pipeline_input_types(
+    working_set: &StateWorkingSet,
+    output: &[Expression],
+    error: &mut Option<ParseError>,
+) {
+    for pair in output.windows(2) {
+        let found = match &pair[0].expr {
+            Expr::Call(call) => working_set.get_decl(call.decl_id).signature().output_type,
+            _ => pair[0].ty.clone(),
+        };
+
+        if let Expr::Call(call) = &pair[1].expr {
+            let expected = working_set.get_decl(call.decl_id).signature().input_type;
+
+            if expected != Type::Any && found != Type::Any && !type_compatible(&expected, &found) {
+                *error = error.take().or(Some(ParseError::InputMismatch(
+                    expected.to_string(),
+                    found.to_string(),
+                    pair[1].span,
+                )));
+            }
+        }
+    }
+}
+
 pub fn parse_block(
     working_set: &mut StateWorkingSet,
     lite_block: &LiteBlock,
@@ -4634,6 +5084,22 @@ pub fn parse_block(
         .enumerate()
         .map(|(idx, pipeline)| {
             if pipeline.commands.len() > 1 {
+                // `alias` swallows the rest of the pipeline as its
+                // replacement, but lite parsing has already split it apart.
+                // Splice the commands (and their pipes) back together so
+                // `parse_alias` records the full replacement.
+                if working_set.get_span_contents(pipeline.commands[0].parts[0]) == b"alias" {
+                    let lite_command = merge_alias_pipeline(working_set, pipeline);
+                    let (pipeline, err) =
+                        parse_builtin_commands(working_set, &lite_command, expand_aliases_denylist);
+
+                    if error.is_none() {
+                        error = err;
+                    }
+
+                    return pipeline;
+                }
+
                 let mut output = pipeline
                     .commands
                     .iter()
@@ -4649,6 +5115,8 @@ pub fn parse_block(
                     })
                     .collect::<Vec<Expression>>();
 
+                check_pipeline_input_types(working_set, &output, &mut error);
+
                 if is_subexpression {
                     for expr in output.iter_mut().skip(1) {
                         if expr.has_in_variable(working_set) {
@@ -4886,6 +5354,20 @@ pub fn discover_captures_in_expr(
                 output.extend(&result);
             }
         }
+        Expr::MatchBlock(matches) => {
+            for (pattern, expr) in matches {
+                // variables bound by the pattern are not captures
+                seen.extend(pattern.variables());
+
+                if let Some(guard) = &pattern.guard {
+                    let result = discover_captures_in_expr(working_set, guard, seen, seen_blocks);
+                    output.extend(&result);
+                }
+
+                let result = discover_captures_in_expr(working_set, expr, seen, seen_blocks);
+                output.extend(&result);
+            }
+        }
         Expr::Operator(_) => {}
         Expr::Range(expr1, expr2, expr3, _) => {
             if let Some(expr) = expr1 {
@@ -4940,6 +5422,10 @@ pub fn discover_captures_in_expr(
                 }
             }
         }
+        Expr::Spread(expr) => {
+            let result = discover_captures_in_expr(working_set, expr, seen, seen_blocks);
+            output.extend(&result);
+        }
         Expr::String(_) => {}
         Expr::StringInterpolation(exprs) => {
             for expr in exprs {
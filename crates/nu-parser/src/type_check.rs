@@ -98,8 +98,10 @@ pub fn math_result_type(
 
                 (Type::Filesize, Type::Int) => (Type::Filesize, None),
                 (Type::Int, Type::Filesize) => (Type::Filesize, None),
-                (Type::Duration, Type::Int) => (Type::Filesize, None),
-                (Type::Int, Type::Duration) => (Type::Filesize, None),
+                (Type::Duration, Type::Int) => (Type::Duration, None),
+                (Type::Int, Type::Duration) => (Type::Duration, None),
+                (Type::Duration, Type::Float) => (Type::Duration, None),
+                (Type::Float, Type::Duration) => (Type::Duration, None),
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
@@ -149,6 +151,7 @@ pub fn math_result_type(
 
                 (Type::Filesize, Type::Int) => (Type::Filesize, None),
                 (Type::Duration, Type::Int) => (Type::Duration, None),
+                (Type::Duration, Type::Float) => (Type::Duration, None),
 
                 (Type::Any, _) => (Type::Any, None),
                 (_, Type::Any) => (Type::Any, None),
@@ -283,6 +286,13 @@ pub fn math_result_type(
             },
             Operator::Equal => (Type::Bool, None),
             Operator::NotEqual => (Type::Bool, None),
+            // Assignments evaluate to nothing; the interesting check (that the
+            // left-hand side is a mutable variable) happens while parsing
+            Operator::Assign
+            | Operator::PlusAssign
+            | Operator::MinusAssign
+            | Operator::MultiplyAssign
+            | Operator::DivideAssign => (Type::Nothing, None),
             Operator::RegexMatch => match (&lhs.ty, &rhs.ty) {
                 (Type::String, Type::String) => (Type::Bool, None),
                 (Type::Any, _) => (Type::Bool, None),
@@ -45,6 +45,11 @@ impl Expression {
                     | Operator::NotIn => 80,
                     Operator::And => 50,
                     Operator::Or => 40,
+                    Operator::Assign
+                    | Operator::PlusAssign
+                    | Operator::MinusAssign
+                    | Operator::MultiplyAssign
+                    | Operator::DivideAssign => 10,
                 }
             }
             _ => 0,
@@ -182,6 +187,14 @@ impl Expression {
                 }
                 false
             }
+            Expr::MatchBlock(matches) => {
+                for (_, expr) in matches {
+                    if expr.has_in_variable(working_set) {
+                        return true;
+                    }
+                }
+                false
+            }
             Expr::StringInterpolation(items) => {
                 for i in items {
                     if i.has_in_variable(working_set) {
@@ -221,6 +234,7 @@ impl Expression {
                 false
             }
             Expr::Signature(_) => false,
+            Expr::Spread(expr) => expr.has_in_variable(working_set),
             Expr::String(_) => false,
             Expr::RowCondition(block_id) | Expr::Subexpression(block_id) => {
                 let block = working_set.get_block(*block_id);
@@ -337,6 +351,11 @@ impl Expression {
                     l.replace_in_variable(working_set, new_var_id)
                 }
             }
+            Expr::MatchBlock(matches) => {
+                for (_, expr) in matches {
+                    expr.replace_in_variable(working_set, new_var_id)
+                }
+            }
             Expr::Operator(_) => {}
             Expr::Range(left, middle, right, ..) => {
                 if let Some(left) = left {
@@ -356,6 +375,7 @@ impl Expression {
                 }
             }
             Expr::Signature(_) => {}
+            Expr::Spread(expr) => expr.replace_in_variable(working_set, new_var_id),
             Expr::String(_) => {}
             Expr::StringInterpolation(items) => {
                 for i in items {
@@ -484,6 +504,11 @@ impl Expression {
                     l.replace_span(working_set, replaced, new_span)
                 }
             }
+            Expr::MatchBlock(matches) => {
+                for (_, expr) in matches {
+                    expr.replace_span(working_set, replaced, new_span)
+                }
+            }
             Expr::Operator(_) => {}
             Expr::Range(left, middle, right, ..) => {
                 if let Some(left) = left {
@@ -503,6 +528,7 @@ impl Expression {
                 }
             }
             Expr::Signature(_) => {}
+            Expr::Spread(expr) => expr.replace_span(working_set, replaced, new_span),
             Expr::String(_) => {}
             Expr::StringInterpolation(items) => {
                 for i in items {
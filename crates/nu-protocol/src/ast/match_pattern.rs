@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use super::Expression;
+use crate::{Span, Type, VarId};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchPattern {
+    pub pattern: Pattern,
+    pub guard: Option<Expression>,
+    pub span: Span,
+}
+
+impl MatchPattern {
+    pub fn variables(&self) -> Vec<VarId> {
+        self.pattern.variables()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Pattern {
+    /// A record destructuring, eg `{name: $name}`
+    Record(Vec<(String, MatchPattern)>),
+    /// A list destructuring, eg `[$head, ..$rest]`
+    List(Vec<MatchPattern>),
+    /// A literal value to compare against, eg `3` or `1..10`
+    Value(Expression),
+    /// A variable binding that matches anything, eg `$x`
+    Variable(VarId),
+    /// A type to check the value against, eg `int`
+    Type(Type),
+    /// The remainder of a list, bound to a variable, eg `..$rest`
+    Rest(VarId),
+    /// The remainder of a list, ignored, eg `..`
+    IgnoreRest,
+    /// A `_` that matches anything without binding it
+    IgnoreValue,
+    Garbage,
+}
+
+impl Pattern {
+    pub fn variables(&self) -> Vec<VarId> {
+        let mut output = vec![];
+        match self {
+            Pattern::Record(items) => {
+                for (_, pattern) in items {
+                    output.append(&mut pattern.variables());
+                }
+            }
+            Pattern::List(items) => {
+                for pattern in items {
+                    output.append(&mut pattern.variables());
+                }
+            }
+            Pattern::Variable(var_id) | Pattern::Rest(var_id) => output.push(*var_id),
+            Pattern::Value(_)
+            | Pattern::Type(_)
+            | Pattern::IgnoreRest
+            | Pattern::IgnoreValue
+            | Pattern::Garbage => {}
+        }
+
+        output
+    }
+}
@@ -4,6 +4,7 @@ mod cell_path;
 mod expr;
 mod expression;
 mod import_pattern;
+mod match_pattern;
 mod operator;
 mod pipeline;
 
@@ -13,5 +14,6 @@ pub use cell_path::*;
 pub use expr::*;
 pub use expression::*;
 pub use import_pattern::*;
+pub use match_pattern::*;
 pub use operator::*;
 pub use pipeline::*;
@@ -24,6 +24,11 @@ pub enum Operator {
     Or,
     Pow,
     StartsWith,
+    Assign,
+    PlusAssign,
+    MinusAssign,
+    MultiplyAssign,
+    DivideAssign,
 }
 
 impl Display for Operator {
@@ -48,6 +53,11 @@ impl Display for Operator {
             Operator::LessThanOrEqual => write!(f, "<="),
             Operator::GreaterThanOrEqual => write!(f, ">="),
             Operator::StartsWith => write!(f, "=^"),
+            Operator::Assign => write!(f, "="),
+            Operator::PlusAssign => write!(f, "+="),
+            Operator::MinusAssign => write!(f, "-="),
+            Operator::MultiplyAssign => write!(f, "*="),
+            Operator::DivideAssign => write!(f, "/="),
         }
     }
 }
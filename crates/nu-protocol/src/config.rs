@@ -24,10 +24,39 @@ pub struct ParsedMenu {
     pub source: Value,
 }
 
+/// Definition of the parsed hooks from the config object
+///
+/// Each hook is kept as a raw Value (a block or a list of blocks) and evaluated lazily at the
+/// point where the hook fires. `env_change` is a record mapping environment variable names to
+/// such values.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Hooks {
+    pub pre_prompt: Option<Value>,
+    pub pre_execution: Option<Value>,
+    pub env_change: Option<Value>,
+}
+
+impl Hooks {
+    pub fn new() -> Self {
+        Self {
+            pre_prompt: None,
+            pre_execution: None,
+            env_change: None,
+        }
+    }
+}
+
+impl Default for Hooks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Config {
     pub filesize_metric: bool,
     pub table_mode: String,
+    pub table_show_index: bool,
     pub use_ls_colors: bool,
     pub color_config: HashMap<String, Value>,
     pub use_grid_icons: bool,
@@ -40,11 +69,16 @@ pub struct Config {
     pub partial_completions: bool,
     pub edit_mode: String,
     pub max_history_size: i64,
+    pub history_file_format: String,
     pub sync_history_on_enter: bool,
     pub log_level: String,
     pub keybindings: Vec<ParsedKeybinding>,
     pub menus: Vec<ParsedMenu>,
+    pub hooks: Hooks,
     pub rm_always_trash: bool,
+    pub table_page_size: i64,
+    pub table_flush_interval: i64,
+    pub recursion_limit: i64,
 }
 
 impl Default for Config {
@@ -52,6 +86,7 @@ impl Default for Config {
         Config {
             filesize_metric: false,
             table_mode: "rounded".into(),
+            table_show_index: true,
             use_ls_colors: true,
             color_config: HashMap::new(),
             use_grid_icons: false,
@@ -64,11 +99,16 @@ impl Default for Config {
             partial_completions: true,
             edit_mode: "emacs".into(),
             max_history_size: 1000,
+            history_file_format: "plaintext".into(),
             sync_history_on_enter: true,
             log_level: String::new(),
             keybindings: Vec::new(),
             menus: Vec::new(),
+            hooks: Hooks::new(),
             rm_always_trash: false,
+            table_page_size: 1000,
+            table_flush_interval: 1000,
+            recursion_limit: 50,
         }
     }
 }
@@ -108,6 +148,13 @@ impl Value {
                             eprintln!("$config.table_mode is not a string")
                         }
                     }
+                    "table_show_index" => {
+                        if let Ok(b) = value.as_bool() {
+                            config.table_show_index = b;
+                        } else {
+                            eprintln!("$config.table_show_index is not a bool")
+                        }
+                    }
                     "use_ls_colors" => {
                         if let Ok(b) = value.as_bool() {
                             config.use_ls_colors = b;
@@ -201,6 +248,13 @@ impl Value {
                             eprintln!("$config.edit_mode is not a string")
                         }
                     }
+                    "history_file_format" => {
+                        if let Ok(v) = value.as_string() {
+                            config.history_file_format = v;
+                        } else {
+                            eprintln!("$config.history_file_format is not a string")
+                        }
+                    }
                     "max_history_size" => {
                         if let Ok(i) = value.as_i64() {
                             config.max_history_size = i;
@@ -215,6 +269,27 @@ impl Value {
                             eprintln!("$config.sync_history_on_enter is not a bool")
                         }
                     }
+                    "recursion_limit" => {
+                        if let Ok(i) = value.as_integer() {
+                            config.recursion_limit = i;
+                        } else {
+                            eprintln!("$config.recursion_limit is not an integer")
+                        }
+                    }
+                    "table_page_size" => {
+                        if let Ok(i) = value.as_integer() {
+                            config.table_page_size = i;
+                        } else {
+                            eprintln!("$config.table_page_size is not an integer")
+                        }
+                    }
+                    "table_flush_interval" => {
+                        if let Ok(i) = value.as_integer() {
+                            config.table_flush_interval = i;
+                        } else {
+                            eprintln!("$config.table_flush_interval is not an integer")
+                        }
+                    }
                     "log_level" => {
                         if let Ok(v) = value.as_string() {
                             config.log_level = v.to_lowercase();
@@ -222,6 +297,13 @@ impl Value {
                             eprintln!("$config.log_level is not a string")
                         }
                     }
+                    "hooks" => match create_hooks(value) {
+                        Ok(hooks) => config.hooks = hooks,
+                        Err(e) => {
+                            eprintln!("$config.hooks is not a valid hooks list");
+                            eprintln!("{:?}", e);
+                        }
+                    },
                     "menus" => match create_menus(value, &config) {
                         Ok(map) => config.menus = map,
                         Err(e) => {
@@ -298,6 +380,46 @@ pub fn color_value_string(
     }
 }
 
+// Parses the config object to extract the hook values (blocks or lists of blocks)
+fn create_hooks(value: &Value) -> Result<Hooks, ShellError> {
+    match value {
+        Value::Record { cols, vals, span } => {
+            let mut hooks = Hooks::new();
+
+            for (col, val) in cols.iter().zip(vals) {
+                match col.as_str() {
+                    "pre_prompt" => hooks.pre_prompt = Some(val.clone()),
+                    "pre_execution" => hooks.pre_execution = Some(val.clone()),
+                    "env_change" => hooks.env_change = Some(val.clone()),
+                    x => {
+                        return Err(ShellError::SpannedLabeledError(
+                            "Invalid hook".to_string(),
+                            format!(
+                                "'{}' is not a hook (expected 'pre_prompt', 'pre_execution', or 'env_change')",
+                                x
+                            ),
+                            *span,
+                        ));
+                    }
+                }
+            }
+
+            Ok(hooks)
+        }
+        v => match v.span() {
+            Ok(span) => Err(ShellError::SpannedLabeledError(
+                "Invalid hook".to_string(),
+                "$config.hooks must be a record".to_string(),
+                span,
+            )),
+            _ => Err(ShellError::LabeledError(
+                "Invalid hook".to_string(),
+                "$config.hooks must be a record".to_string(),
+            )),
+        },
+    }
+}
+
 // Parses the config object to extract the strings that will compose a keybinding for reedline
 fn create_keybindings(value: &Value, config: &Config) -> Result<Vec<ParsedKeybinding>, ShellError> {
     match value {
@@ -341,12 +463,28 @@ pub fn create_menus(value: &Value, config: &Config) -> Result<Vec<ParsedMenu>, S
         Value::Record { cols, vals, span } => {
             // Finding the modifier value in the record
             let name = extract_value("name", cols, vals, span)?.clone();
-            let marker = extract_value("marker", cols, vals, span)?.clone();
-            let only_buffer_difference =
-                extract_value("only_buffer_difference", cols, vals, span)?.clone();
             let style = extract_value("style", cols, vals, span)?.clone();
             let menu_type = extract_value("type", cols, vals, span)?.clone();
 
+            // Marker is an optional value
+            let marker = match extract_value("marker", cols, vals, span) {
+                Ok(marker) => marker.clone(),
+                Err(_) => Value::String {
+                    val: "| ".to_string(),
+                    span: *span,
+                },
+            };
+
+            // Only buffer difference is an optional value
+            let only_buffer_difference =
+                match extract_value("only_buffer_difference", cols, vals, span) {
+                    Ok(only_buffer_difference) => only_buffer_difference.clone(),
+                    Err(_) => Value::Bool {
+                        val: false,
+                        span: *span,
+                    },
+                };
+
             // Source is an optional value
             let source = match extract_value("source", cols, vals, span) {
                 Ok(source) => source.clone(),
@@ -176,6 +176,10 @@ pub struct EngineState {
     pub scope: im::Vector<ScopeFrame>,
     pub ctrlc: Option<Arc<AtomicBool>>,
     pub env_vars: im::HashMap<String, Value>,
+    pub is_interactive: bool,
+    pub is_login: bool,
+    /// Identifies the shell session for structured history backends
+    pub history_session_id: i64,
     #[cfg(feature = "plugin")]
     pub plugin_signatures: Option<PathBuf>,
 }
@@ -192,11 +196,11 @@ impl EngineState {
             files: im::vector![],
             file_contents: im::vector![],
             vars: im::vector![
-                Variable::new(Span::new(0, 0), Type::Any),
-                Variable::new(Span::new(0, 0), Type::Any),
-                Variable::new(Span::new(0, 0), Type::Any),
-                Variable::new(Span::new(0, 0), Type::Any),
-                Variable::new(Span::new(0, 0), Type::Any)
+                Variable::new(Span::new(0, 0), Type::Any, false),
+                Variable::new(Span::new(0, 0), Type::Any, false),
+                Variable::new(Span::new(0, 0), Type::Any, false),
+                Variable::new(Span::new(0, 0), Type::Any, false),
+                Variable::new(Span::new(0, 0), Type::Any, false)
             ],
             decls: im::vector![],
             aliases: im::vector![],
@@ -205,6 +209,9 @@ impl EngineState {
             scope: im::vector![ScopeFrame::new()],
             ctrlc: None,
             env_vars: im::HashMap::new(),
+            is_interactive: false,
+            is_login: false,
+            history_session_id: 0,
             #[cfg(feature = "plugin")]
             plugin_signatures: None,
         }
@@ -1186,7 +1193,13 @@ impl<'a> StateWorkingSet<'a> {
         None
     }
 
-    pub fn add_variable(&mut self, mut name: Vec<u8>, span: Span, ty: Type) -> VarId {
+    pub fn add_variable(
+        &mut self,
+        mut name: Vec<u8>,
+        span: Span,
+        ty: Type,
+        mutable: bool,
+    ) -> VarId {
         let next_id = self.next_var_id();
 
         // correct name if necessary
@@ -1202,7 +1215,7 @@ impl<'a> StateWorkingSet<'a> {
 
         last.vars.insert(name, next_id);
 
-        self.delta.vars.push(Variable::new(span, ty));
+        self.delta.vars.push(Variable::new(span, ty, mutable));
 
         next_id
     }
@@ -1243,6 +1256,15 @@ impl<'a> StateWorkingSet<'a> {
         }
     }
 
+    pub fn set_variable_const_val(&mut self, var_id: VarId, val: Value) {
+        let num_permanent_vars = self.permanent_state.num_vars();
+        if var_id < num_permanent_vars {
+            panic!("Internal error: attempted to set into permanent state from working set")
+        } else {
+            self.delta.vars[var_id - num_permanent_vars].const_val = Some(val);
+        }
+    }
+
     pub fn get_variable(&self, var_id: VarId) -> &Variable {
         let num_permanent_vars = self.permanent_state.num_vars();
         if var_id < num_permanent_vars {
@@ -29,6 +29,9 @@ pub struct Stack {
     /// Tells which environment variables from engine state are hidden. We don't need to track the
     /// env vars in the stack since we can just delete them.
     pub env_hidden: HashSet<String>,
+    /// How deep we are in nested custom command calls; checked against the
+    /// 'recursion_limit' config value by the evaluator
+    pub recursion_count: i64,
 }
 
 impl Default for Stack {
@@ -43,6 +46,7 @@ impl Stack {
             vars: HashMap::new(),
             env_vars: vec![],
             env_hidden: HashSet::new(),
+            recursion_count: 0,
         }
     }
 
@@ -90,6 +94,7 @@ impl Stack {
 
     pub fn captures_to_stack(&self, captures: &HashMap<VarId, Value>) -> Stack {
         let mut output = Stack::new();
+        output.recursion_count = self.recursion_count;
 
         output.vars = captures.clone();
 
@@ -107,6 +112,7 @@ impl Stack {
 
     pub fn gather_captures(&self, captures: &[VarId]) -> Stack {
         let mut output = Stack::new();
+        output.recursion_count = self.recursion_count;
 
         let fake_span = Span::new(0, 0);
 
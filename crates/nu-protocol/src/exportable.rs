@@ -1,7 +1,8 @@
-use crate::{AliasId, BlockId, DeclId};
+use crate::{AliasId, BlockId, DeclId, OverlayId};
 
 pub enum Exportable {
     Decl(DeclId),
     Alias(AliasId),
     EnvVar(BlockId),
+    Overlay(OverlayId),
 }
@@ -1,4 +1,4 @@
-use crate::{AliasId, BlockId, DeclId, Span};
+use crate::{AliasId, BlockId, DeclId, OverlayId, Span};
 
 use indexmap::IndexMap;
 
@@ -11,6 +11,7 @@ pub struct Overlay {
     pub decls: IndexMap<Vec<u8>, DeclId>,
     pub aliases: IndexMap<Vec<u8>, AliasId>,
     pub env_vars: IndexMap<Vec<u8>, BlockId>,
+    pub overlays: IndexMap<Vec<u8>, OverlayId>,
     pub span: Option<Span>,
 }
 
@@ -20,6 +21,7 @@ impl Overlay {
             decls: IndexMap::new(),
             aliases: IndexMap::new(),
             env_vars: IndexMap::new(),
+            overlays: IndexMap::new(),
             span: None,
         }
     }
@@ -29,6 +31,7 @@ impl Overlay {
             decls: IndexMap::new(),
             aliases: IndexMap::new(),
             env_vars: IndexMap::new(),
+            overlays: IndexMap::new(),
             span: Some(span),
         }
     }
@@ -45,9 +48,14 @@ impl Overlay {
         self.env_vars.insert(name.to_vec(), block_id)
     }
 
+    pub fn add_overlay(&mut self, name: &[u8], overlay_id: OverlayId) -> Option<OverlayId> {
+        self.overlays.insert(name.to_vec(), overlay_id)
+    }
+
     pub fn extend(&mut self, other: &Overlay) {
         self.decls.extend(other.decls.clone());
         self.env_vars.extend(other.env_vars.clone());
+        self.overlays.extend(other.overlays.clone());
     }
 
     pub fn is_empty(&self) -> bool {
@@ -70,6 +78,14 @@ impl Overlay {
         self.aliases.contains_key(name)
     }
 
+    pub fn get_overlay_id(&self, name: &[u8]) -> Option<OverlayId> {
+        self.overlays.get(name).copied()
+    }
+
+    pub fn has_overlay(&self, name: &[u8]) -> bool {
+        self.overlays.contains_key(name)
+    }
+
     pub fn decl_name_with_head(&self, name: &[u8], head: &[u8]) -> Option<Vec<u8>> {
         if self.has_decl(name) {
             let mut new_name = head.to_vec();
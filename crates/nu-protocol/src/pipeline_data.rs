@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::{atomic::AtomicBool, Arc};
 
 use crate::{ast::PathMember, Config, ListStream, RawStream, ShellError, Span, Value};
@@ -52,6 +53,7 @@ pub struct PipelineMetadata {
 #[derive(Debug, Clone)]
 pub enum DataSource {
     Ls,
+    FilePath(PathBuf),
 }
 
 impl PipelineData {
@@ -196,11 +198,14 @@ impl PipelineData {
 
                 let mut output = String::new();
                 for item in items {
-                    match item.as_string() {
-                        Ok(s) => output.push_str(&s),
-                        Err(err) => {
-                            return Err(err);
+                    match item {
+                        // The external may emit invalid utf-8; a command that
+                        // asks for text gets a lossy conversion of those bytes
+                        // instead of an error
+                        Value::Binary { val, .. } => {
+                            output.push_str(&String::from_utf8_lossy(&val))
                         }
+                        item => output.push_str(&item.as_string()?),
                     }
                 }
 
@@ -2,7 +2,7 @@ use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{ast::Operator, Span, Type};
+use crate::{ast::Operator, Span, Type, Value};
 
 /// The fundamental error type for the evaluation engine. These cases represent different kinds of errors
 /// the evaluator might face, along with helpful spans to label. An error renderer will take this error value
@@ -38,6 +38,15 @@ pub enum ShellError {
     #[diagnostic(code(nu::shell::type_mismatch), url(docsrs))]
     TypeMismatch(String, #[label = "needs {0}"] Span),
 
+    #[error("Type mismatch for parameter '{0}'")]
+    #[diagnostic(code(nu::shell::parameter_type_mismatch), url(docsrs))]
+    ParameterTypeMismatch(
+        String,
+        String,
+        String,
+        #[label("expected {1}, found {2}")] Span,
+    ),
+
     #[error("Unsupported operator: {0}.")]
     #[diagnostic(code(nu::shell::unsupported_operator), url(docsrs))]
     UnsupportedOperator(Operator, #[label = "unsupported operator"] Span),
@@ -133,6 +142,14 @@ Either make sure {0} is a string, or add a 'to_string' entry for it in ENV_CONVE
     )]
     EnvVarNotAString(String, #[label("value not representable as a string")] Span),
 
+    #[error("List spread not supported.")]
+    #[diagnostic(code(nu::shell::cannot_spread_as_list), url(docsrs))]
+    CannotSpreadAsList(#[label = "cannot spread value unless it's a list"] Span),
+
+    #[error("Record spread not supported.")]
+    #[diagnostic(code(nu::shell::cannot_spread_as_record), url(docsrs))]
+    CannotSpreadAsRecord(#[label = "cannot spread value unless it's a record"] Span),
+
     #[error("Division by zero.")]
     #[diagnostic(code(nu::shell::division_by_zero), url(docsrs))]
     DivisionByZero(#[label("division by zero")] Span),
@@ -347,6 +364,31 @@ Either make sure {0} is a string, or add a 'to_string' entry for it in ENV_CONVE
         String,
         #[label = "'{0}' is deprecated. Please use '{1}' instead."] Span,
     ),
+
+    // Used for the break command. This isn't an error so much as a signal that the enclosing
+    #[error("Recursion limit ({0}) reached")]
+    #[diagnostic(
+        code(nu::shell::recursion_limit_reached),
+        url(docsrs),
+        help("the limit can be changed with the 'recursion_limit' config value")
+    )]
+    RecursionLimitReached(i64, #[label = "exceeded the recursion limit"] Span),
+
+    // loop should stop; it is caught by loop, while, and for in the evaluator.
+    #[error("Break used outside of loop")]
+    #[diagnostic(code(nu::shell::break_outside_of_loop), url(docsrs))]
+    Break(#[label = "used outside of loop"] Span),
+
+    // Used for the continue command, along the same lines as `Break` above.
+    #[error("Continue used outside of loop")]
+    #[diagnostic(code(nu::shell::continue_outside_of_loop), url(docsrs))]
+    Continue(#[label = "used outside of loop"] Span),
+
+    // Used for the return command. This isn't an error so much as an early return from the
+    // current custom command; it is caught at the command boundary in the evaluator.
+    #[error("Return used outside of custom command")]
+    #[diagnostic(code(nu::shell::return_outside_of_custom_command), url(docsrs))]
+    Return(#[label = "used outside of custom command"] Span, Box<Value>),
 }
 
 impl From<std::io::Error> for ShellError {
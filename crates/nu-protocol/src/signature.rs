@@ -10,6 +10,7 @@ use crate::BlockId;
 use crate::PipelineData;
 use crate::ShellError;
 use crate::SyntaxShape;
+use crate::Type;
 use crate::VarId;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -101,6 +102,8 @@ pub struct Signature {
     pub optional_positional: Vec<PositionalArg>,
     pub rest_positional: Option<PositionalArg>,
     pub named: Vec<Flag>,
+    pub input_type: Type,
+    pub output_type: Type,
     pub is_filter: bool,
     pub creates_scope: bool,
     // Signature category used to classify commands stored in the list of declarations
@@ -142,6 +145,8 @@ impl Signature {
             optional_positional: vec![],
             rest_positional: None,
             named: vec![flag],
+            input_type: Type::Any,
+            output_type: Type::Any,
             is_filter: false,
             creates_scope: false,
             category: Category::Default,
@@ -170,6 +175,18 @@ impl Signature {
         self
     }
 
+    /// Declare the type of pipeline input the command accepts
+    pub fn input_type(mut self, input_type: Type) -> Signature {
+        self.input_type = input_type;
+        self
+    }
+
+    /// Declare the type of pipeline output the command produces
+    pub fn output_type(mut self, output_type: Type) -> Signature {
+        self.output_type = output_type;
+        self
+    }
+
     /// Update signature's fields from a Command trait implementation
     pub fn update_from_command(mut self, command: &dyn Command) -> Signature {
         self.search_terms = command
@@ -71,6 +71,9 @@ pub enum SyntaxShape {
     /// A general math expression, eg `1 + 2`
     MathExpression,
 
+    /// A block of matches, used by `match`, eg `{ 1 => 'yes!', _ => 'no!' }`
+    MatchBlock,
+
     /// A variable name
     Variable,
 
@@ -116,6 +119,7 @@ impl SyntaxShape {
             }
             SyntaxShape::Keyword(_, expr) => expr.to_type(),
             SyntaxShape::MathExpression => Type::Any,
+            SyntaxShape::MatchBlock => Type::Any,
             SyntaxShape::Number => Type::Number,
             SyntaxShape::Operator => Type::Any,
             SyntaxShape::Range => Type::Any,
@@ -158,6 +162,7 @@ impl Display for SyntaxShape {
             SyntaxShape::Operator => write!(f, "operator"),
             SyntaxShape::RowCondition => write!(f, "condition"),
             SyntaxShape::MathExpression => write!(f, "variable"),
+            SyntaxShape::MatchBlock => write!(f, "match-block"),
             SyntaxShape::Variable => write!(f, "var"),
             SyntaxShape::VarWithOptType => write!(f, "vardecl"),
             SyntaxShape::Signature => write!(f, "signature"),
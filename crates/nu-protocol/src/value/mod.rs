@@ -386,7 +386,12 @@ impl Value {
                     match &ty {
                         Some(x) => {
                             if &val_ty != x {
-                                ty = Some(Type::Any)
+                                if x.is_numeric() && val_ty.is_numeric() {
+                                    // A mix of int and float is still numeric
+                                    ty = Some(Type::Number)
+                                } else {
+                                    ty = Some(Type::Any)
+                                }
                             }
                         }
                         None => ty = Some(val_ty),
@@ -446,7 +451,7 @@ impl Value {
             Value::Bool { val, .. } => val.to_string(),
             Value::Int { val, .. } => val.to_string(),
             Value::Float { val, .. } => val.to_string(),
-            Value::Filesize { val, .. } => format_filesize(*val, config),
+            Value::Filesize { val, .. } => format_filesize_from_conf(*val, config),
             Value::Duration { val, .. } => format_duration(*val),
             Value::Date { val, .. } => format!("{} ({})", val.to_rfc2822(), HumanTime::from(*val)),
             Value::Range { val, .. } => {
@@ -487,7 +492,7 @@ impl Value {
             Value::Bool { val, .. } => val.to_string(),
             Value::Int { val, .. } => val.to_string(),
             Value::Float { val, .. } => val.to_string(),
-            Value::Filesize { val, .. } => format_filesize(*val, config),
+            Value::Filesize { val, .. } => format_filesize_from_conf(*val, config),
             Value::Duration { val, .. } => format_duration(*val),
             Value::Date { val, .. } => HumanTime::from(*val).to_string(),
             Value::Range { val, .. } => {
@@ -535,7 +540,7 @@ impl Value {
             Value::Bool { val, .. } => val.to_string(),
             Value::Int { val, .. } => val.to_string(),
             Value::Float { val, .. } => val.to_string(),
-            Value::Filesize { val, .. } => format_filesize(*val, config),
+            Value::Filesize { val, .. } => format_filesize_from_conf(*val, config),
             Value::Duration { val, .. } => format_duration(*val),
             Value::Date { val, .. } => format!("{:?}", val),
             Value::Range { val, .. } => {
@@ -1610,6 +1615,18 @@ impl Value {
                     span,
                 })
             }
+            (Value::Float { val: lhs, .. }, Value::Duration { val: rhs, .. }) => {
+                Ok(Value::Duration {
+                    val: (*lhs * *rhs as f64) as i64,
+                    span,
+                })
+            }
+            (Value::Duration { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
+                Ok(Value::Duration {
+                    val: (*lhs as f64 * *rhs) as i64,
+                    span,
+                })
+            }
             (Value::CustomValue { val: lhs, span }, rhs) => {
                 lhs.operation(*span, Operator::Multiply, op, rhs)
             }
@@ -1716,6 +1733,16 @@ impl Value {
                     Err(ShellError::DivisionByZero(op))
                 }
             }
+            (Value::Duration { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
+                if *rhs != 0.0 {
+                    Ok(Value::Duration {
+                        val: (*lhs as f64 / *rhs) as i64,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero(op))
+                }
+            }
             (Value::Duration { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
                 if *rhs != 0 {
                     Ok(Value::Duration {
@@ -2297,14 +2324,23 @@ pub fn format_duration(duration: i64) -> String {
     )
 }
 
-fn format_filesize(num_bytes: i64, config: &Config) -> String {
+/// Format a filesize using the format/metric settings from the config
+pub fn format_filesize_from_conf(num_bytes: i64, config: &Config) -> String {
+    format_filesize(num_bytes, &config.filesize_format, config.filesize_metric)
+}
+
+/// Format a filesize from an integer amount of bytes, using the given unit
+///
+/// The unit can be one of b, kb, kib, mb, mib, ..., zib. Any other value formats with an
+/// automatically chosen unit ("auto").
+pub fn format_filesize(num_bytes: i64, format_value: &str, filesize_metric: bool) -> String {
     // Allow the user to specify how they want their numbers formatted
-    let filesize_format_var = get_config_filesize_format(config);
+    let filesize_format_var = get_filesize_format(format_value, filesize_metric);
 
     let byte = byte_unit::Byte::from_bytes(num_bytes as u128);
     let adj_byte =
         if filesize_format_var.0 == byte_unit::ByteUnit::B && filesize_format_var.1 == "auto" {
-            byte.get_appropriate_unit(!config.filesize_metric)
+            byte.get_appropriate_unit(!filesize_metric)
         } else {
             byte.get_adjusted_unit(filesize_format_var.0)
         };
@@ -2343,13 +2379,13 @@ fn format_filesize(num_bytes: i64, config: &Config) -> String {
     }
 }
 
-fn get_config_filesize_format(config: &Config) -> (ByteUnit, &str) {
+fn get_filesize_format(format_value: &str, filesize_metric: bool) -> (ByteUnit, &str) {
     // We need to take into account config.filesize_metric so, if someone asks for KB
     // filesize_metric is true, return KiB
-    let filesize_format = match config.filesize_format.as_str() {
+    let filesize_format = match format_value {
         "b" => (byte_unit::ByteUnit::B, ""),
         "kb" => {
-            if config.filesize_metric {
+            if filesize_metric {
                 (byte_unit::ByteUnit::KiB, "")
             } else {
                 (byte_unit::ByteUnit::KB, "")
@@ -2357,7 +2393,7 @@ fn get_config_filesize_format(config: &Config) -> (ByteUnit, &str) {
         }
         "kib" => (byte_unit::ByteUnit::KiB, ""),
         "mb" => {
-            if config.filesize_metric {
+            if filesize_metric {
                 (byte_unit::ByteUnit::MiB, "")
             } else {
                 (byte_unit::ByteUnit::MB, "")
@@ -2365,7 +2401,7 @@ fn get_config_filesize_format(config: &Config) -> (ByteUnit, &str) {
         }
         "mib" => (byte_unit::ByteUnit::MiB, ""),
         "gb" => {
-            if config.filesize_metric {
+            if filesize_metric {
                 (byte_unit::ByteUnit::GiB, "")
             } else {
                 (byte_unit::ByteUnit::GB, "")
@@ -2373,7 +2409,7 @@ fn get_config_filesize_format(config: &Config) -> (ByteUnit, &str) {
         }
         "gib" => (byte_unit::ByteUnit::GiB, ""),
         "tb" => {
-            if config.filesize_metric {
+            if filesize_metric {
                 (byte_unit::ByteUnit::TiB, "")
             } else {
                 (byte_unit::ByteUnit::TB, "")
@@ -2381,7 +2417,7 @@ fn get_config_filesize_format(config: &Config) -> (ByteUnit, &str) {
         }
         "tib" => (byte_unit::ByteUnit::TiB, ""),
         "pb" => {
-            if config.filesize_metric {
+            if filesize_metric {
                 (byte_unit::ByteUnit::PiB, "")
             } else {
                 (byte_unit::ByteUnit::PB, "")
@@ -2389,7 +2425,7 @@ fn get_config_filesize_format(config: &Config) -> (ByteUnit, &str) {
         }
         "pib" => (byte_unit::ByteUnit::PiB, ""),
         "eb" => {
-            if config.filesize_metric {
+            if filesize_metric {
                 (byte_unit::ByteUnit::EiB, "")
             } else {
                 (byte_unit::ByteUnit::EB, "")
@@ -2397,7 +2433,7 @@ fn get_config_filesize_format(config: &Config) -> (ByteUnit, &str) {
         }
         "eib" => (byte_unit::ByteUnit::EiB, ""),
         "zb" => {
-            if config.filesize_metric {
+            if filesize_metric {
                 (byte_unit::ByteUnit::ZiB, "")
             } else {
                 (byte_unit::ByteUnit::ZB, "")
@@ -48,7 +48,12 @@ impl RawStream {
         let span = self.span;
 
         for item in self {
-            output.push_str(&item?.as_string()?);
+            match item? {
+                // Lossily convert any raw bytes that turned out not to be
+                // utf-8; asking for a string is asking for text
+                Value::Binary { val, .. } => output.push_str(&String::from_utf8_lossy(&val)),
+                item => output.push_str(&item.as_string()?),
+            }
         }
 
         Ok(Spanned { item: output, span })
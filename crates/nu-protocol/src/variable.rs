@@ -1,16 +1,21 @@
-use crate::{Span, Type};
+use crate::{Span, Type, Value};
 
 #[derive(Clone, Debug)]
 pub struct Variable {
     pub declaration_span: Span,
     pub ty: Type,
+    pub mutable: bool,
+    /// The value of a `const` variable, known at parse time
+    pub const_val: Option<Value>,
 }
 
 impl Variable {
-    pub fn new(declaration_span: Span, ty: Type) -> Variable {
+    pub fn new(declaration_span: Span, ty: Type, mutable: bool) -> Variable {
         Self {
             declaration_span,
             ty,
+            mutable,
+            const_val: None,
         }
     }
 }
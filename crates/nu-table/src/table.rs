@@ -945,7 +945,24 @@ impl WrappedTable {
                     }
                 }
             }
-            _ => {} // Never and Auto aka auto get eaten and nothing happens
+            FooterMode::Auto => {
+                let height = terminal_size::terminal_size()
+                    .map(|(_, terminal_size::Height(h))| h as usize)
+                    .unwrap_or(0);
+
+                // Only repeat the header when the table is taller than the
+                // screen, which is when the top header has scrolled away.
+                if height > 0 && self.data.len() + 3 > height {
+                    if self.theme.separate_header && !self.headers.is_empty() && !skip_headers {
+                        output.push_str(&self.print_separator(SeparatorPosition::Middle, color_hm));
+                    }
+
+                    if !self.headers.is_empty() && !skip_headers {
+                        output.push_str(&self.print_cell_contents(&self.footer, color_hm));
+                    }
+                }
+            }
+            FooterMode::Never => {}
         }
 
         // The table finish
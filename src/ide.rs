@@ -0,0 +1,126 @@
+use nu_cli::NuCompleter;
+use nu_parser::{flatten_block, parse, FlatShape};
+use nu_protocol::{
+    engine::{EngineState, Stack, StateWorkingSet},
+    Span, Value, CONFIG_VARIABLE_ID,
+};
+use reedline::Completer;
+use std::sync::Arc;
+
+/// Escape a string for the hand-built JSON emitted for IDE consumers
+fn json_escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn read_script(file_path: &str) -> Vec<u8> {
+    match std::fs::read(file_path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("Could not read file '{}': {:?}", file_path, err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parse the file and emit any diagnostic as a JSON record with spans relative
+/// to the start of the file
+pub fn check(engine_state: &mut EngineState, file_path: &str) {
+    let file = read_script(file_path);
+    let offset = engine_state.next_span_start();
+    let mut working_set = StateWorkingSet::new(engine_state);
+
+    let (_, err) = parse(&mut working_set, Some(file_path), &file, false, &[]);
+
+    if let Some(err) = err {
+        let span = err.span();
+        println!(
+            "{{\"type\": \"diagnostic\", \"severity\": \"Error\", \"message\": \"{}\", \"span\": {{\"start\": {}, \"end\": {}}}}}",
+            json_escape(&err.to_string()),
+            span.start.saturating_sub(offset),
+            span.end.saturating_sub(offset)
+        );
+    }
+}
+
+/// Describe whatever sits under the given cursor position as a JSON record
+pub fn hover(engine_state: &mut EngineState, file_path: &str, location: usize) {
+    let file = read_script(file_path);
+    let offset = engine_state.next_span_start();
+    let mut working_set = StateWorkingSet::new(engine_state);
+
+    let (block, _) = parse(&mut working_set, Some(file_path), &file, false, &[]);
+
+    let location = location + offset;
+
+    for (span, shape) in flatten_block(&working_set, &block) {
+        if location >= span.start && location < span.end {
+            let hover = match shape {
+                FlatShape::InternalCall | FlatShape::External | FlatShape::Custom(_) => {
+                    let name = working_set.get_span_contents(span);
+                    if let Some(decl_id) = working_set.find_decl(name) {
+                        working_set.get_decl(decl_id).usage().to_string()
+                    } else {
+                        String::from_utf8_lossy(name).to_string()
+                    }
+                }
+                // FlatShape renders as the color config key (e.g. "shape_int");
+                // strip the prefix to leave just the shape name
+                shape => shape.to_string().trim_start_matches("shape_").to_string(),
+            };
+
+            println!(
+                "{{\"hover\": \"{}\", \"span\": {{\"start\": {}, \"end\": {}}}}}",
+                json_escape(&hover),
+                span.start.saturating_sub(offset),
+                span.end.saturating_sub(offset)
+            );
+            return;
+        }
+    }
+}
+
+/// Emit the completions for the given cursor position as a JSON list
+pub fn complete(engine_state: EngineState, file_path: &str, location: usize) {
+    let file = read_script(file_path);
+    let line = String::from_utf8_lossy(&file).to_string();
+
+    let mut stack = Stack::new();
+    stack.add_var(
+        CONFIG_VARIABLE_ID,
+        Value::Record {
+            cols: vec![],
+            vals: vec![],
+            span: Span::new(0, 0),
+        },
+    );
+
+    let mut completer = NuCompleter::new(Arc::new(engine_state), stack, None);
+
+    let suggestions = completer
+        .complete(&line, location)
+        .into_iter()
+        .map(|suggestion| {
+            format!(
+                "{{\"value\": \"{}\", \"description\": \"{}\", \"span\": {{\"start\": {}, \"end\": {}}}}}",
+                json_escape(&suggestion.value),
+                json_escape(suggestion.description.as_deref().unwrap_or("")),
+                suggestion.span.start,
+                suggestion.span.end
+            )
+        })
+        .collect::<Vec<String>>();
+
+    println!("[{}]", suggestions.join(", "));
+}
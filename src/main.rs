@@ -1,4 +1,5 @@
 mod config_files;
+mod ide;
 mod logger;
 mod test_bins;
 #[cfg(test)]
@@ -26,7 +27,7 @@ use nu_protocol::{
 };
 use std::cell::RefCell;
 use std::{
-    io::{BufReader, Write},
+    io::{BufReader, Read, Write},
     path::Path,
     sync::{
         atomic::{AtomicBool, Ordering},
@@ -72,6 +73,12 @@ fn main() -> Result<()> {
     engine_state.ctrlc = Some(engine_state_ctrlc);
     // End ctrl-c protection section
 
+    // Identify this shell session for structured history backends
+    engine_state.history_session_id = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0);
+
     let mut args_to_nushell = vec![];
     let mut script_name = String::new();
     let mut args_to_script = vec![];
@@ -104,6 +111,8 @@ fn main() -> Result<()> {
                 || arg == "--env-config"
                 || arg == "--threads"
                 || arg == "-t"
+                || arg == "--ide-complete"
+                || arg == "--ide-hover"
             {
                 collect_arg_nushell = true;
             }
@@ -123,7 +132,7 @@ fn main() -> Result<()> {
         parse_commandline_args(&nushell_commandline_args, &init_cwd, &mut engine_state);
 
     match parsed_nu_cli_args {
-        Ok(binary_args) => {
+        Ok(mut binary_args) => {
             if let Some(t) = binary_args.threads {
                 // 0 means to let rayon decide how many threads to use
                 let threads = t.as_i64().unwrap_or(0);
@@ -169,6 +178,50 @@ fn main() -> Result<()> {
                 }
                 std::process::exit(0)
             }
+
+            if binary_args.ide_check
+                || binary_args.ide_hover.is_some()
+                || binary_args.ide_complete.is_some()
+            {
+                gather_parent_env_vars(&mut engine_state);
+
+                if binary_args.ide_check {
+                    ide::check(&mut engine_state, &script_name);
+                } else if let Some(location) = &binary_args.ide_hover {
+                    ide::hover(
+                        &mut engine_state,
+                        &script_name,
+                        location.as_i64().unwrap_or(0) as usize,
+                    );
+                } else if let Some(location) = &binary_args.ide_complete {
+                    ide::complete(
+                        engine_state,
+                        &script_name,
+                        location.as_i64().unwrap_or(0) as usize,
+                    );
+                }
+
+                return Ok(());
+            }
+
+            // With no commands and no script file, `--stdin` means the script
+            // itself arrives on stdin, so nu can be the target of heredocs and
+            // code generators: echo 'ls | length' | nu --stdin
+            if binary_args.commands.is_none() && script_name.is_empty() {
+                if let Some(redirect_stdin) = binary_args.redirect_stdin.take() {
+                    let mut script = String::new();
+                    if let Err(err) = std::io::stdin().read_to_string(&mut script) {
+                        eprintln!("Error: could not read script from stdin: {}", err);
+                        std::process::exit(1);
+                    }
+
+                    binary_args.commands = Some(Spanned {
+                        item: script,
+                        span: redirect_stdin.span,
+                    });
+                }
+            }
+
             let input = if let Some(redirect_stdin) = &binary_args.redirect_stdin {
                 let stdin = std::io::stdin();
                 let buf_reader = BufReader::new(stdin);
@@ -192,6 +245,10 @@ fn main() -> Result<()> {
                 info!("redirect_stdin {}:{}:{}", file!(), line!(), column!());
             }
 
+            engine_state.is_login = binary_args.login_shell.is_some();
+            engine_state.is_interactive = binary_args.interactive_shell.is_some()
+                || (binary_args.commands.is_none() && script_name.is_empty());
+
             // First, set up env vars as strings only
             gather_parent_env_vars(&mut engine_state);
             let mut stack = nu_protocol::engine::Stack::new();
@@ -250,12 +307,14 @@ fn main() -> Result<()> {
 
                 ret_val
             } else {
-                setup_config(
-                    &mut engine_state,
-                    &mut stack,
-                    binary_args.config_file,
-                    binary_args.env_file,
-                );
+                if binary_args.no_config_file.is_none() {
+                    setup_config(
+                        &mut engine_state,
+                        &mut stack,
+                        binary_args.config_file,
+                        binary_args.env_file,
+                    );
+                }
                 let history_path = config_files::create_history_path();
 
                 let ret_val =
@@ -336,6 +395,7 @@ fn parse_commandline_args(
             let redirect_stdin = call.get_named_arg("stdin");
             let login_shell = call.get_named_arg("login");
             let interactive_shell = call.get_named_arg("interactive");
+            let no_config_file = call.get_named_arg("no-config-file");
             let commands: Option<Expression> = call.get_flag_expr("commands");
             let testbin: Option<Expression> = call.get_flag_expr("testbin");
             let perf = call.has_flag("perf");
@@ -343,6 +403,10 @@ fn parse_commandline_args(
             let env_file: Option<Expression> = call.get_flag_expr("env-config");
             let log_level: Option<Expression> = call.get_flag_expr("log-level");
             let threads: Option<Value> = call.get_flag(engine_state, &mut stack, "threads")?;
+            let ide_check = call.has_flag("ide-check");
+            let ide_hover: Option<Value> = call.get_flag(engine_state, &mut stack, "ide-hover")?;
+            let ide_complete: Option<Value> =
+                call.get_flag(engine_state, &mut stack, "ide-complete")?;
 
             fn extract_contents(
                 expression: Option<Expression>,
@@ -394,6 +458,7 @@ fn parse_commandline_args(
                 redirect_stdin,
                 login_shell,
                 interactive_shell,
+                no_config_file,
                 commands,
                 testbin,
                 config_file,
@@ -401,6 +466,9 @@ fn parse_commandline_args(
                 log_level,
                 perf,
                 threads,
+                ide_check,
+                ide_hover,
+                ide_complete,
             });
         }
     }
@@ -416,6 +484,7 @@ struct NushellCliArgs {
     #[allow(dead_code)]
     login_shell: Option<Spanned<String>>,
     interactive_shell: Option<Spanned<String>>,
+    no_config_file: Option<Spanned<String>>,
     commands: Option<Spanned<String>>,
     testbin: Option<Spanned<String>>,
     config_file: Option<Spanned<String>>,
@@ -423,6 +492,9 @@ struct NushellCliArgs {
     log_level: Option<Spanned<String>>,
     perf: bool,
     threads: Option<Value>,
+    ide_check: bool,
+    ide_hover: Option<Value>,
+    ide_complete: Option<Value>,
 }
 
 #[derive(Clone)]
@@ -439,6 +511,11 @@ impl Command for Nu {
             .switch("stdin", "redirect the stdin", None)
             .switch("login", "start as a login shell", Some('l'))
             .switch("interactive", "start as an interactive shell", Some('i'))
+            .switch(
+                "no-config-file",
+                "start with no config file and no env file",
+                Some('n'),
+            )
             .switch("version", "print the version", Some('v'))
             .switch(
                 "perf",
@@ -481,6 +558,23 @@ impl Command for Nu {
                 "threads to use for parallel commands",
                 Some('t'),
             )
+            .switch(
+                "ide-check",
+                "parse the script file and print diagnostics as JSON (for IDE integration)",
+                None,
+            )
+            .named(
+                "ide-hover",
+                SyntaxShape::Int,
+                "print hover information for the item at the given position in the script file as JSON (for IDE integration)",
+                None,
+            )
+            .named(
+                "ide-complete",
+                SyntaxShape::Int,
+                "print completions for the given position in the script file as JSON (for IDE integration)",
+                None,
+            )
             .optional(
                 "script file",
                 SyntaxShape::Filepath,
@@ -5,11 +5,13 @@ mod test_engine;
 mod test_env;
 mod test_hiding;
 mod test_iteration;
+mod test_match;
 mod test_math;
 mod test_modules;
 mod test_parser;
 mod test_ranges;
 mod test_regex;
+mod test_spread;
 mod test_strings;
 mod test_table_operations;
 mod test_type_check;
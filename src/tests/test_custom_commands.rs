@@ -95,6 +95,84 @@ fn custom_switch4() -> TestResult {
     )
 }
 
+#[test]
+fn default_param_value() -> TestResult {
+    run_test(r#"def foo [x: int = 5] { $x + 1 }; foo"#, "6")
+}
+
+#[test]
+fn default_param_value_overridden() -> TestResult {
+    run_test(r#"def foo [x: int = 5] { $x + 1 }; foo 10"#, "11")
+}
+
+#[test]
+fn typed_rest_param() -> TestResult {
+    run_test(
+        r#"def foo [...rest: int] { $rest.0 + $rest.1 }; foo 10 80"#,
+        "90",
+    )
+}
+
+#[test]
+fn typed_rest_param_wrong_type() -> TestResult {
+    fail_test(
+        r#"def foo [...rest: int] { $rest }; foo hello"#,
+        "expected int",
+    )
+}
+
+#[test]
+fn flag_short_alias() -> TestResult {
+    run_test(
+        r#"def foo [--bob(-b): int] { $bob + 100 }; foo -b 55"#,
+        "155",
+    )
+}
+
+#[test]
+fn flag_default_value() -> TestResult {
+    run_test(r#"def foo [--bob: int = 3] { $bob + 100 }; foo"#, "103")
+}
+
+#[test]
+fn switch_default_value_applies_when_missing() -> TestResult {
+    run_test(
+        r#"def florb [--dry-run: bool = true] { if ($dry-run) { "foo" } else { "bar" } }; florb"#,
+        "foo",
+    )
+}
+
+#[test]
+fn switch_overrides_its_default_value() -> TestResult {
+    run_test(
+        r#"def florb [--dry-run = false] { if ($dry-run) { "foo" } else { "bar" } }; florb --dry-run"#,
+        "foo",
+    )
+}
+
+#[test]
+fn early_return() -> TestResult {
+    run_test(r#"def foo [x] { if $x { return 2 }; 5 }; foo true"#, "2")
+}
+
+#[test]
+fn early_return_fallthrough() -> TestResult {
+    run_test(r#"def foo [x] { if $x { return 2 }; 5 }; foo false"#, "5")
+}
+
+#[test]
+fn early_return_unwinds_nested_blocks() -> TestResult {
+    run_test(
+        r#"def foo [] { for x in [1 2 3] { if $x == 2 { return $x } } }; foo"#,
+        "2",
+    )
+}
+
+#[test]
+fn return_outside_of_custom_command() -> TestResult {
+    fail_test(r#"return 2"#, "Return used outside of custom command")
+}
+
 #[test]
 fn simple_var_closing() -> TestResult {
     run_test("let $x = 10; def foo [] { $x }; foo", "10")
@@ -117,3 +195,29 @@ fn allow_missing_optional_params() -> TestResult {
         "5",
     )
 }
+
+#[test]
+fn recursion_limit_is_catchable() -> TestResult {
+    fail_test(
+        r#"def bomb [] { bomb }; bomb"#,
+        "Recursion limit (50) reached",
+    )
+}
+
+#[test]
+fn type_check_positional_args() -> TestResult {
+    fail_test(
+        r#"def foo [x: int] { $x }; foo ('"a"' | from json)"#,
+        "Type mismatch for parameter 'x'",
+    )
+}
+
+#[test]
+fn type_check_allows_any() -> TestResult {
+    run_test(r#"def foo [x] { $x }; foo ('"a"' | from json)"#, "a")
+}
+
+#[test]
+fn type_check_accepts_matching_type() -> TestResult {
+    run_test(r#"def foo [x: int] { $x + 1 }; foo ('1' | from json)"#, "2")
+}
@@ -374,3 +374,48 @@ fn better_operator_spans() -> TestResult {
         "true",
     )
 }
+
+#[test]
+fn mut_variable() -> TestResult {
+    run_test(r#"mut x = 3; $x = $x + 1; $x"#, "4")
+}
+
+#[test]
+fn mut_add_assign() -> TestResult {
+    run_test(r#"mut y = 3; $y += 2; $y"#, "5")
+}
+
+#[test]
+fn mut_minus_assign() -> TestResult {
+    run_test(r#"mut y = 3; $y -= 2; $y"#, "1")
+}
+
+#[test]
+fn mut_multiply_assign() -> TestResult {
+    run_test(r#"mut y = 3; $y *= 2; $y"#, "6")
+}
+
+#[test]
+fn mut_divide_assign() -> TestResult {
+    run_test(r#"mut y = 8; $y /= 2; $y"#, "4")
+}
+
+#[test]
+fn mut_in_loop() -> TestResult {
+    run_test(r#"mut x = 0; while $x < 3 { $x += 1 }; $x"#, "3")
+}
+
+#[test]
+fn mut_in_if() -> TestResult {
+    run_test(r#"mut x = 10; if true { $x = 20 }; $x"#, "20")
+}
+
+#[test]
+fn assignment_to_immutable_var() -> TestResult {
+    fail_test(r#"let x = 3; $x = 4"#, "immutable")
+}
+
+#[test]
+fn assignment_to_non_var() -> TestResult {
+    fail_test(r#"1 = 2"#, "require a variable")
+}
@@ -1,4 +1,4 @@
-use crate::tests::{run_test, TestResult};
+use crate::tests::{fail_test, run_test, TestResult};
 
 #[test]
 fn better_block_types() -> TestResult {
@@ -37,6 +37,43 @@ fn for_loops() -> TestResult {
     run_test(r#"(for x in [1, 2, 3] { $x + 10 }).1"#, "12")
 }
 
+#[test]
+fn for_break() -> TestResult {
+    run_test(
+        r#"(for x in [1, 2, 3, 4] { if $x > 2 { break }; $x }) | math sum"#,
+        "3",
+    )
+}
+
+#[test]
+fn for_continue() -> TestResult {
+    run_test(
+        r#"(for x in [1, 2, 3, 4] { if $x == 2 { continue }; $x }) | math sum"#,
+        "8",
+    )
+}
+
+#[test]
+fn while_loop() -> TestResult {
+    run_test(
+        r#"let-env x = "aaaa"; while ($env.x | str length) < 6 { let-env x = $"($env.x)a" }; $env.x"#,
+        "aaaaaa",
+    )
+}
+
+#[test]
+fn loop_with_break() -> TestResult {
+    run_test(
+        r#"let-env x = "aaaa"; loop { if ($env.x | str length) > 5 { break }; let-env x = $"($env.x)a" }; $env.x"#,
+        "aaaaaa",
+    )
+}
+
+#[test]
+fn break_outside_of_loop() -> TestResult {
+    fail_test(r#"break"#, "Break used outside of loop")
+}
+
 #[test]
 fn par_each() -> TestResult {
     run_test(
@@ -0,0 +1,109 @@
+use crate::tests::{run_test, TestResult};
+
+#[test]
+fn match_for_range() -> TestResult {
+    run_test(r#"match 3 { 1..10 => { 'yes!' } }"#, "yes!")
+}
+
+#[test]
+fn match_for_range_unmatched() -> TestResult {
+    run_test(r#"match 11 { 1..10 => { 'yes!' }, _ => { 'no!' } }"#, "no!")
+}
+
+#[test]
+fn match_for_record() -> TestResult {
+    run_test(
+        r#"match {a: 100} { {a: $my_value} => { $my_value } }"#,
+        "100",
+    )
+}
+
+#[test]
+fn match_for_record_shorthand() -> TestResult {
+    run_test(
+        r#"match {a: 100, b: 200} { {a: $a, b: $b} => { $a + $b } }"#,
+        "300",
+    )
+}
+
+#[test]
+fn match_list() -> TestResult {
+    run_test(
+        r#"match [1, 2] { [$b] => { $"one: ($b)" }, [$b, $c] => { $"two: ($b) ($c)" } }"#,
+        "two: 1 2",
+    )
+}
+
+#[test]
+fn match_list_rest_ignore() -> TestResult {
+    run_test(
+        r#"match [1, 2] { [$b, ..] => { $"one: ($b)" }, [$b, $c] => { $"two: ($b) ($c)" } }"#,
+        "one: 1",
+    )
+}
+
+#[test]
+fn match_list_rest() -> TestResult {
+    run_test(
+        r#"match [1, 2, 3] { [$b, ..$remainder] => { $"single: ($b) ($remainder | math sum)" } }"#,
+        "single: 1 5",
+    )
+}
+
+#[test]
+fn match_constant_1() -> TestResult {
+    run_test(
+        r#"match 2 { 1 => { 'spam!' }, 2 => { 'eggs!' }, 3 => { 'bacon!' } }"#,
+        "eggs!",
+    )
+}
+
+#[test]
+fn match_constant_2() -> TestResult {
+    run_test(
+        r#"match "bacon" { "spam" => { 'spam!' }, "eggs" => { 'eggs!' }, "bacon" => { 'bacon!' } }"#,
+        "bacon!",
+    )
+}
+
+#[test]
+fn match_null() -> TestResult {
+    run_test(
+        r#"match null { null => { 'success!' } _ => { 'failure!' } }"#,
+        "success!",
+    )
+}
+
+#[test]
+fn match_type() -> TestResult {
+    run_test(
+        r#"match [1, 2, 3] { int => { 'int' }, list => { 'list' }, _ => { 'other' } }"#,
+        "list",
+    )
+}
+
+#[test]
+fn match_guard() -> TestResult {
+    run_test(
+        r#"match 5 { $x if $x > 3 => { 'big' }, _ => { 'small' } }"#,
+        "big",
+    )
+}
+
+#[test]
+fn match_guard_fallthrough() -> TestResult {
+    run_test(
+        r#"match 2 { $x if $x > 3 => { 'big' }, $x => { $x * 10 } }"#,
+        "20",
+    )
+}
+
+#[test]
+fn match_no_arm_returns_nothing() -> TestResult {
+    run_test(r#"match 5 { 1 => { 'yes!' } } | describe"#, "nothing")
+}
+
+#[test]
+fn match_doesnt_overwrite_variable() -> TestResult {
+    run_test(r#"let b = 100; match 55 { $b => {} }; $b"#, "100")
+}
@@ -127,3 +127,43 @@ fn export_alias() -> TestResult {
         "hello",
     )
 }
+
+#[test]
+fn export_submodule() -> TestResult {
+    run_test(
+        r#"module spam { export module eggs { export def foo [] { 3 } } }; use spam; spam eggs foo"#,
+        "3",
+    )
+}
+
+#[test]
+fn use_submodule_name() -> TestResult {
+    run_test(
+        r#"module spam { export module eggs { export def foo [] { 3 } } }; use spam eggs; eggs foo"#,
+        "3",
+    )
+}
+
+#[test]
+fn use_submodule_deep_name() -> TestResult {
+    run_test(
+        r#"module spam { export module eggs { export def foo [] { 3 } } }; use spam eggs foo; foo"#,
+        "3",
+    )
+}
+
+#[test]
+fn use_submodule_glob() -> TestResult {
+    run_test(
+        r#"module spam { export module eggs { export def foo [] { 3 } } }; use spam eggs *; foo"#,
+        "3",
+    )
+}
+
+#[test]
+fn private_submodule() -> TestResult {
+    run_test(
+        r#"module spam { module eggs { export def foo [] { 3 } }; export def lunch [] { 4 } }; use spam lunch; lunch"#,
+        "4",
+    )
+}
@@ -406,3 +406,66 @@ fn unary_not_6() -> TestResult {
 fn date_literal() -> TestResult {
     run_test(r#"2022-09-10 | date to-record | get day"#, "10")
 }
+
+#[test]
+fn hex_binary_literal() -> TestResult {
+    run_test(r#"(0x[de ad be ef]).3"#, "239")
+}
+
+#[test]
+fn octal_binary_literal() -> TestResult {
+    run_test(r#"0o[377] == 0x[ff]"#, "true")
+}
+
+#[test]
+fn bit_binary_literal() -> TestResult {
+    run_test(r#"0b[00010010] == 0x[12]"#, "true")
+}
+
+#[test]
+fn incomplete_binary_literal() -> TestResult {
+    fail_test(r#"0b[010]"#, "multiple of 8")
+}
+
+#[test]
+fn binary_index_access() -> TestResult {
+    run_test(r#"let x = 0x[de ad be ef]; $x.1"#, "173")
+}
+
+#[test]
+fn const_bool() -> TestResult {
+    run_test(r#"const x = false; $x"#, "false")
+}
+
+#[test]
+fn const_int() -> TestResult {
+    run_test(r#"const x = 20; $x"#, "20")
+}
+
+#[test]
+fn const_list() -> TestResult {
+    run_test(r#"const x = [a b c]; $x | length"#, "3")
+}
+
+#[test]
+fn const_record() -> TestResult {
+    run_test(r#"const x = { a: 10, b: 20 }; $x.b"#, "20")
+}
+
+#[test]
+fn const_references_const() -> TestResult {
+    run_test(r#"const x = 5; const y = $x; $y"#, "5")
+}
+
+#[test]
+fn const_not_a_constant() -> TestResult {
+    fail_test(r#"const x = (ls | length)"#, "not a constant")
+}
+
+#[test]
+fn const_in_source() -> TestResult {
+    fail_test(
+        r#"const file = 'nonexistent-const-source.nu'; source $file"#,
+        "File not found",
+    )
+}
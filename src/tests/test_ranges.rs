@@ -34,3 +34,43 @@ fn range_and_reduction() -> TestResult {
 fn zip_ranges() -> TestResult {
     run_test(r#"1..3 | zip 4..6 | get 2.1"#, "6")
 }
+
+#[test]
+fn range_with_variable_bounds() -> TestResult {
+    run_test(r#"let a = 1; let b = 5; $a..$b | math sum"#, "15")
+}
+
+#[test]
+fn range_with_subexpression_bounds() -> TestResult {
+    run_test(r#"(1 + 1)..(2 + 3) | math sum"#, "14")
+}
+
+#[test]
+fn range_with_mixed_bounds() -> TestResult {
+    run_test(r#"let x = 10; 8..$x | math sum"#, "27")
+}
+
+#[test]
+fn stepped_range_with_variable_step() -> TestResult {
+    run_test(r#"let s = 2; 0..$s..10 | math sum"#, "30")
+}
+
+#[test]
+fn open_ended_stepped_range_is_lazy() -> TestResult {
+    run_test(r#"0..2.. | take 5 | math sum"#, "20")
+}
+
+#[test]
+fn descending_open_ended_range_is_lazy() -> TestResult {
+    run_test(r#"5..4.. | first 3 | math sum"#, "12")
+}
+
+#[test]
+fn descending_range() -> TestResult {
+    run_test(r#"10..8 | math sum"#, "27")
+}
+
+#[test]
+fn zero_step_range_is_an_error() -> TestResult {
+    fail_test(r#"0..0..10"#, "countable")
+}
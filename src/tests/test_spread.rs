@@ -0,0 +1,50 @@
+use crate::tests::{fail_test, run_test, TestResult};
+
+#[test]
+fn spread_in_list() -> TestResult {
+    run_test(r#"[1 2 ...[3 4] 5] | math sum"#, "15")
+}
+
+#[test]
+fn spread_variable_in_list() -> TestResult {
+    run_test(r#"let x = [3 4]; [1 2 ...$x] | length"#, "4")
+}
+
+#[test]
+fn spread_subexpression_in_list() -> TestResult {
+    run_test(r#"[1 ...(seq 2 4)] | math sum"#, "10")
+}
+
+#[test]
+fn spread_non_list_in_list() -> TestResult {
+    fail_test(r#"let x = 2; [1 ...$x]"#, "spread")
+}
+
+#[test]
+fn spread_in_record() -> TestResult {
+    run_test(
+        r#"let config = {a: 1, b: 2}; {...$config, c: 3} | get b"#,
+        "2",
+    )
+}
+
+#[test]
+fn spread_non_record_in_record() -> TestResult {
+    fail_test(r#"{...[1, 2]}"#, "spread")
+}
+
+#[test]
+fn spread_into_rest_parameter() -> TestResult {
+    run_test(
+        r#"def total [...nums: int] { $nums | math sum }; let x = [1 2 3]; total ...$x 4"#,
+        "10",
+    )
+}
+
+#[test]
+fn spread_into_external_args() -> TestResult {
+    run_test(
+        r#"let x = ["hello" "world"]; ^echo ...$x | str trim"#,
+        "hello world",
+    )
+}
@@ -24,3 +24,21 @@ fn number_int() -> TestResult {
 fn number_float() -> TestResult {
     run_test(r#"def foo [x:number] { $x }; foo 1.4"#, "1.4")
 }
+
+#[test]
+fn input_mismatch_in_pipeline() -> TestResult {
+    fail_test(r#"[1 2 3] | from json"#, "expects string input")
+}
+
+#[test]
+fn input_check_allows_matching_type() -> TestResult {
+    run_test(r#"'[1, 2, 3]' | from json | length"#, "3")
+}
+
+#[test]
+fn input_check_uses_inferred_def_output() -> TestResult {
+    fail_test(
+        r#"def foo [] { 42 }; foo | detect columns"#,
+        "expects string input",
+    )
+}
@@ -87,6 +87,15 @@ fn passes_with_env_env_var_to_external_process() {
     assert_eq!(actual.out, "foo");
 }
 
+#[test]
+fn passes_env_var_converted_by_env_conversions_to_external_process() {
+    let actual = nu!(cwd: ".", r#"
+        let-env ENV_CONVERSIONS = { "FOO": { from_string: { |s| $s | split row ':' } to_string: { |v| $v | str collect ':' } } }
+        with-env [FOO [a b c]] {nu --testbin echo_env FOO}
+        "#);
+    assert_eq!(actual.out, "a:b:c");
+}
+
 // FIXME: autoenv not currently implemented
 #[ignore]
 #[test]
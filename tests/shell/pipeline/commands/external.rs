@@ -107,6 +107,14 @@ fn passes_binary_data_between_externals() {
     )
 }
 
+#[test]
+fn lossily_converts_binary_data_when_text_is_required() {
+    let actual =
+        nu!(cwd: "tests/fixtures/formats", r#"nu --testbin meowb sample.db | size | get bytes"#);
+
+    assert!(actual.out.parse::<i64>().is_ok());
+}
+
 mod it_evaluation {
     use super::nu;
     use nu_test_support::fs::Stub::{EmptyFile, FileWithContent, FileWithContentToBeTrimmed};
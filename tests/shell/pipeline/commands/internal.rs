@@ -402,6 +402,63 @@ fn let_env_hides_variable_in_parent_scope() {
     assert!(actual.err.contains("did you mean"));
 }
 
+#[test]
+fn hide_env_hides_variable() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let-env TESTENVVAR = "hello world"
+            echo $env.TESTENVVAR
+            hide-env TESTENVVAR
+            echo $env.TESTENVVAR
+        "#
+    );
+
+    assert_eq!(actual.out, "hello world");
+    assert!(actual.err.contains("did you mean"));
+}
+
+#[test]
+fn hide_env_does_not_leak_from_scope() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            let-env TESTENVVAR = "hello world"
+            do {
+                hide-env TESTENVVAR
+            }
+            echo $env.TESTENVVAR
+        "#
+    );
+
+    assert_eq!(actual.out, "hello world");
+}
+
+#[test]
+fn hide_env_nonexistent_variable_errors() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            hide-env NONEXISTENT_VARIABLE
+        "#
+    );
+
+    assert!(actual.err.contains("not found"));
+}
+
+#[test]
+fn hide_env_nonexistent_variable_ignore_errors() {
+    let actual = nu!(
+        cwd: ".",
+        r#"
+            hide-env --ignore-errors NONEXISTENT_VARIABLE
+            echo "ok"
+        "#
+    );
+
+    assert_eq!(actual.out, "ok");
+}
+
 #[test]
 fn unlet_env_variable() {
     let actual = nu!(